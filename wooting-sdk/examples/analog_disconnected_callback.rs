@@ -1,30 +1,24 @@
 use std::process::exit;
-use std::thread::sleep;
 use std::time::Duration;
 
-use wooting_sdk::{analog, Key};
+use wooting_sdk::{analog, ConnectionWatcher};
 
 fn main() {
+    // The watcher reads from the keyboard in the background, so that a disconnect is noticed.
+    let watcher = ConnectionWatcher::new();
+
     println!("Waiting until keyboard is connected...");
-    loop {
-        // Only continue if keyboard is connected.
-        if analog::is_wooting_keyboard_connected() {
-            break;
-        }
-    }
+    while !watcher.wait_for_connection(Duration::from_secs(1)) {}
     println!("Connected...");
 
-    println!("Setting callback...");
-    analog::set_disconnected_callback(|| {
+    println!("Subscribing callback...");
+    // The callback stays subscribed until the subscription is dropped.
+    let _subscription = analog::subscribe_disconnected(|| {
         println!("Callback triggered...");
         println!("Finished!");
         exit(0);
     });
 
     println!("Waiting until disconnect...");
-    loop {
-        // Trigger a read so that a disconnect will be noticed. This is a limitation of that API.
-        let _ = analog::read_analog_key(Key::Escape);
-        sleep(Duration::from_millis(1000));
-    }
+    for _ in watcher.events() {}
 }
@@ -0,0 +1,24 @@
+use std::thread::sleep;
+use std::time::Duration;
+
+use wooting_sdk::{
+    rgb::{Color, RgbFrame, RgbKeyboard},
+    Key,
+};
+
+fn main() {
+    println!(
+        "Keyboard connected? {}",
+        wooting_sdk::rgb::is_wooting_keyboard_connected()
+    );
+    let mut keyboard = RgbKeyboard::open().unwrap();
+    // Sweep a white key across a blue keyboard, reusing the same frame.
+    let mut frame = RgbFrame::new(Color::BLUE);
+    for key in Key::iter() {
+        frame[key] = Color::WHITE;
+        println!("Committing {:?}... {:?}", key, keyboard.commit(&frame));
+        frame[key] = Color::BLUE;
+        sleep(Duration::from_millis(50));
+    }
+    println!("Finished!");
+}
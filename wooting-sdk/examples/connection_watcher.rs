@@ -0,0 +1,37 @@
+use std::time::Duration;
+
+use wooting_sdk::{
+    rgb::{Color, RgbKeyboard},
+    ConnectionEvent, ConnectionWatcher, KeySet,
+};
+
+fn main() {
+    let watcher = ConnectionWatcher::new();
+
+    println!("Waiting until keyboard is connected...");
+    if !watcher.wait_for_connection(Duration::from_secs(30)) {
+        println!("No keyboard was connected!");
+        return;
+    }
+    println!("Connected...");
+
+    println!("Lighting up WASD...");
+    let mut keyboard = RgbKeyboard::open().unwrap();
+    keyboard.array_fill(KeySet::WASD, Color::RED).unwrap();
+    keyboard.array_update().unwrap();
+
+    println!("Unplug and plug in the keyboard, or press Ctrl+C to finish...");
+    for event in watcher.events() {
+        match event {
+            ConnectionEvent::Connected => {
+                println!("Connected...");
+                // Replay WASD straight away, as nothing else is written to the keyboard. The RGB
+                // SDK may not have noticed the unplug, so `poll_reconnect` would not replay it.
+                if let Err(error) = keyboard.restore() {
+                    println!("Restoring colors failed: {}", error);
+                }
+            }
+            ConnectionEvent::Disconnected => println!("Disconnected..."),
+        }
+    }
+}
@@ -8,36 +8,36 @@ fn main() {
         "Keyboard connected? {}",
         rgb::is_wooting_keyboard_connected()
     );
-    let mut keyboard = rgb::RgbKeyboard::default();
+    let mut keyboard = rgb::RgbKeyboard::open().unwrap();
 
-    keyboard.direct_set_key(Key::Q, 255, 255, 255);
+    keyboard.direct_set_key(Key::Q, (255, 255, 255)).unwrap();
     sleep(Duration::from_millis(1000));
-    keyboard.direct_reset_key(Key::Q);
+    keyboard.direct_reset_key(Key::Q).unwrap();
     sleep(Duration::from_millis(1000));
 
-    keyboard.direct_set_key(Key::W, 255, 255, 255);
+    keyboard.direct_set_key(Key::W, (255, 255, 255)).unwrap();
     sleep(Duration::from_millis(1000));
-    keyboard.direct_reset_key(Key::W);
+    keyboard.direct_reset_key(Key::W).unwrap();
     sleep(Duration::from_millis(1000));
 
-    keyboard.direct_set_key(Key::E, 255, 255, 255);
+    keyboard.direct_set_key(Key::E, (255, 255, 255)).unwrap();
     sleep(Duration::from_millis(1000));
-    keyboard.direct_reset_key(Key::E);
+    keyboard.direct_reset_key(Key::E).unwrap();
     sleep(Duration::from_millis(1000));
 
-    keyboard.direct_set_key(Key::R, 255, 255, 255);
+    keyboard.direct_set_key(Key::R, (255, 255, 255)).unwrap();
     sleep(Duration::from_millis(1000));
-    keyboard.direct_reset_key(Key::R);
+    keyboard.direct_reset_key(Key::R).unwrap();
     sleep(Duration::from_millis(1000));
 
-    keyboard.direct_set_key(Key::T, 255, 255, 255);
+    keyboard.direct_set_key(Key::T, (255, 255, 255)).unwrap();
     sleep(Duration::from_millis(1000));
-    keyboard.direct_reset_key(Key::T);
+    keyboard.direct_reset_key(Key::T).unwrap();
     sleep(Duration::from_millis(1000));
 
-    keyboard.direct_set_key(Key::Y, 255, 255, 255);
+    keyboard.direct_set_key(Key::Y, (255, 255, 255)).unwrap();
     sleep(Duration::from_millis(1000));
-    keyboard.direct_reset_key(Key::Y);
+    keyboard.direct_reset_key(Key::Y).unwrap();
     sleep(Duration::from_millis(1000));
 
     println!("Finished!");
@@ -1,37 +1,36 @@
 use std::process::exit;
-use std::thread::sleep;
 use std::time::Duration;
 
-use wooting_sdk::{rgb, Key};
+use wooting_sdk::{
+    rgb::{self, Color},
+    ConnectionEvent, ConnectionWatcher, Key,
+};
 
 fn main() {
+    let watcher = ConnectionWatcher::new();
+
     println!("Waiting until keyboard is connected...");
-    loop {
-        // Only continue if keyboard is connected.
-        if rgb::is_wooting_keyboard_connected() {
-            break;
-        }
-    }
+    // Only continue if keyboard is connected, blocking rather than spinning until it is.
+    while !watcher.wait_for_connection(Duration::from_secs(60)) {}
+    let events = watcher.events();
     println!("Connected...");
 
-    println!("Setting callback...");
-    rgb::set_disconnected_callback(|| {
+    println!("Subscribing callback...");
+    // The callback stays subscribed until the subscription is dropped.
+    let _subscription = rgb::subscribe_disconnected(|| {
         println!("Callback triggered...");
         println!("Finished!");
         exit(0);
     });
 
     println!("Waiting until disconnect...");
-    let mut keyboard = rgb::RgbKeyboard::default();
-    let mut set = true;
-    loop {
-        // Trigger a read so that a disconnect will be noticed. This is a limitation of that API.
-        if set {
-            keyboard.direct_reset_key(Key::Escape);
-        } else {
-            keyboard.direct_set_key(Key::Escape, 255, 255, 255);
+    let mut keyboard = rgb::RgbKeyboard::open().unwrap();
+    keyboard.direct_set_key(Key::Escape, Color::WHITE).unwrap();
+    for event in events {
+        if event == ConnectionEvent::Disconnected {
+            // The RGB SDK only notices a disconnect when a write fails. This is a limitation of
+            // that API.
+            let _ = keyboard.direct_reset_key(Key::Escape);
         }
-        set = !set;
-        sleep(Duration::from_millis(1000));
     }
 }
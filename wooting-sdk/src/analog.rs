@@ -0,0 +1,361 @@
+//! Contains functions from Wooting's Analog SDK.
+
+use super::backend::{AnalogBackend, FfiBackend, Subscription};
+use super::{
+    FromScanIndex, IntoMatrixRowColumn, Key, KeyMap, KeySet, KeyboardModel, PhysicalLayout,
+    WootingError,
+};
+
+use std::mem;
+use std::sync::{Mutex, PoisonError};
+
+/// Is there a Wooting keyboard connected?
+///
+/// ```rust,no_run
+/// // Assert that a Wooting keyboard is connected..
+/// assert!(wooting_sdk::analog::is_wooting_keyboard_connected());
+/// ```
+pub fn is_wooting_keyboard_connected() -> bool {
+    AnalogReader::default().is_connected()
+}
+
+/// Subscribe a callback to be invoked when a keyboard is disconnected, until the returned
+/// subscription is dropped. Currently only happens on a failed read. Any number of callbacks can
+/// be subscribed, and a callback that panics does not stop the others from being invoked.
+///
+/// See [`analog_disconnected_callback`][example] example for usage.
+///
+/// [example]: https://github.com/davidtwco/rust-wooting-sdk/blob/master/wooting-sdk/examples/analog_disconnected_callback.rs
+pub fn subscribe_disconnected<F: 'static + Fn() + Send + Sync>(callback: F) -> Subscription {
+    AnalogReader::default().subscribe_disconnected(callback)
+}
+
+/// Set a callback to be invoked when a keyboard is disconnected. Currently only happens on a
+/// failed read.
+///
+/// The callback stays subscribed until the program exits, and a later call subscribes another
+/// callback rather than replacing it.
+#[deprecated(note = "use `subscribe_disconnected`, which can unsubscribe the callback")]
+pub fn set_disconnected_callback<F: 'static + Fn() + Send>(callback: F) {
+    // The callback only has to be `Send`, so it is locked to be shared between threads.
+    let callback = Mutex::new(callback);
+    mem::forget(subscribe_disconnected(move || {
+        (callback.lock().unwrap_or_else(PoisonError::into_inner))()
+    }));
+}
+
+/// Read the analog value, represented by a `u8`, of the requested key.
+///
+/// ```rust,no_run
+/// # fn test() -> Result<(), wooting_sdk::WootingError> {
+/// use wooting_sdk::{analog::read_analog_key, Key};
+///
+/// let min = u8::min_value();
+/// let max = u8::max_value();
+///
+/// // Check how far down W has been pressed..
+/// match read_analog_key(Key::W)? {
+///     min => { /* ..not pressed. */ },
+///     max => { /* ..completely pressed. */ },
+///     _ => { /* ..partially pressed. */ },
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn read_analog_key<K: IntoMatrixRowColumn>(key: K) -> Result<u8, WootingError> {
+    AnalogReader::default().read_analog_key(key)
+}
+
+/// Read the analog value, represented by a `u8`, of pressed keys, up to a maximum of
+/// `n` keys (maximum of sixteen). Keys are read from an ANSI keyboard, see
+/// `AnalogReader::set_layout` to read `ISO1` instead of `Backslash`.
+///
+/// This function will return `Err(WootingError::InvalidBufferSize)` if `n` is zero or larger
+/// than sixteen.
+///
+/// ```rust,no_run
+/// # fn test() -> Result<(), wooting_sdk::WootingError> {
+/// use wooting_sdk::{analog::read_analog_keys, Key};
+///
+/// // Read the value of two pressed keys and check if they are CTRL and A..
+/// if let &[(Key::LeftControl, ctrl), (Key::A, a)] = read_analog_keys(2)?.as_slice() {
+///     // ..if so, check if they are at least half pressed..
+///     let is_half_pressed = |v: u8| v >= u8::max_value() / 2;
+///     if is_half_pressed(ctrl) && is_half_pressed(a) {
+///         // ..and if they are, select all.
+///         select_all();
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// # fn select_all() {}
+/// ```
+pub fn read_analog_keys<K: FromScanIndex>(n: u8) -> Result<Vec<(K, u8)>, WootingError> {
+    AnalogReader::default().read_analog_keys(n)
+}
+
+/// Reads analog values from a keyboard through a backend. The free functions in this module
+/// use an `AnalogReader` with the default [`FfiBackend`][ffi].
+///
+/// Readers are aware of the model of keyboard that they read from, which is the Wooting Two
+/// unless otherwise specified. Reading a key that the model does not have is an error.
+///
+/// Readers are also aware of the physical layout of the keyboard, which is ANSI unless otherwise
+/// specified. `Backslash` and `ISO1` share a scan index, so the layout decides which of the two
+/// `read_analog_keys` reads.
+///
+/// ```rust
+/// # fn test() -> Result<(), wooting_sdk::WootingError> {
+/// use wooting_sdk::{analog::AnalogReader, backend::SimulatedKeyboard, Key};
+///
+/// let keyboard = SimulatedKeyboard::new();
+/// let reader = AnalogReader::new(keyboard.clone());
+///
+/// // Press A halfway down and E completely..
+/// keyboard.set_analog_value(Key::A, 127);
+/// keyboard.set_analog_value(Key::E, 255);
+///
+/// // ..and check that both are read.
+/// assert_eq!(reader.read_analog_key(Key::A)?, 127);
+/// assert_eq!(reader.read_analog_keys(16)?, vec![(Key::E, 255), (Key::A, 127)]);
+/// # Ok(())
+/// # }
+/// # test().unwrap();
+/// ```
+///
+/// [ffi]: ../backend/struct.FfiBackend.html
+#[derive(Clone, Debug)]
+pub struct AnalogReader<B: AnalogBackend = FfiBackend> {
+    backend: B,
+    model: KeyboardModel,
+    layout: PhysicalLayout,
+}
+
+impl<B: AnalogBackend> AnalogReader<B> {
+    /// Create an `AnalogReader` that reads from a Wooting Two through the given backend.
+    pub fn new(backend: B) -> Self {
+        Self::with_model(backend, KeyboardModel::default())
+    }
+
+    /// Create an `AnalogReader` that reads from the given model of keyboard through the given
+    /// backend.
+    ///
+    /// ```rust
+    /// use wooting_sdk::{
+    ///     analog::AnalogReader, backend::SimulatedKeyboard, Key, KeyboardModel, WootingError,
+    /// };
+    ///
+    /// let reader = AnalogReader::with_model(SimulatedKeyboard::new(), KeyboardModel::WootingOne);
+    /// assert_eq!(reader.read_analog_key(Key::Escape), Ok(0));
+    /// assert_eq!(
+    ///     reader.read_analog_key(Key::NumOne),
+    ///     Err(WootingError::UnsupportedKey {
+    ///         model: KeyboardModel::WootingOne,
+    ///         row: 4,
+    ///         column: 17,
+    ///     })
+    /// );
+    /// ```
+    pub fn with_model(backend: B, model: KeyboardModel) -> Self {
+        AnalogReader {
+            backend,
+            model,
+            layout: PhysicalLayout::default(),
+        }
+    }
+
+    /// Return the model of keyboard that this reader reads from.
+    pub fn model(&self) -> KeyboardModel {
+        self.model
+    }
+
+    /// Set the model of keyboard that this reader reads from.
+    pub fn set_model(&mut self, model: KeyboardModel) {
+        self.model = model;
+    }
+
+    /// Return the physical layout of the keyboard that this reader reads from.
+    pub fn layout(&self) -> PhysicalLayout {
+        self.layout
+    }
+
+    /// Set the physical layout of the keyboard that this reader reads from.
+    ///
+    /// ```rust
+    /// # fn test() -> Result<(), wooting_sdk::WootingError> {
+    /// use wooting_sdk::{analog::AnalogReader, backend::SimulatedKeyboard, Key, PhysicalLayout};
+    ///
+    /// let keyboard = SimulatedKeyboard::new();
+    /// let mut reader = AnalogReader::new(keyboard.clone());
+    ///
+    /// // Backslash is read on an ANSI keyboard..
+    /// keyboard.set_analog_value(Key::Backslash, 255);
+    /// assert_eq!(reader.read_analog_keys(16)?, vec![(Key::Backslash, 255)]);
+    ///
+    /// // ..and ISO1, which has the same scan index, on an ISO keyboard.
+    /// reader.set_layout(PhysicalLayout::Iso);
+    /// assert_eq!(reader.read_analog_keys(16)?, vec![(Key::ISO1, 255)]);
+    /// # Ok(())
+    /// # }
+    /// # test().unwrap();
+    /// ```
+    pub fn set_layout(&mut self, layout: PhysicalLayout) {
+        self.layout = layout;
+    }
+
+    /// Return the backend that this reader reads through.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Is there a Wooting keyboard connected?
+    pub fn is_connected(&self) -> bool {
+        self.backend.is_connected()
+    }
+
+    /// Subscribe a callback to be invoked when a keyboard is disconnected, until the returned
+    /// subscription is dropped. Currently only happens on a failed read.
+    pub fn subscribe_disconnected<F: 'static + Fn() + Send + Sync>(
+        &self,
+        callback: F,
+    ) -> Subscription {
+        self.backend.subscribe_disconnected(Box::new(callback))
+    }
+
+    /// Read the analog value, represented by a `u8`, of the requested key.
+    ///
+    /// This function will return `Err(WootingError::UnsupportedKey)` if the keyboard model does
+    /// not have the key.
+    pub fn read_analog_key<K: IntoMatrixRowColumn>(&self, key: K) -> Result<u8, WootingError> {
+        let (row, column) = self.model.matrix_row_and_column(&key)?;
+        let ret = self.backend.read_analog(row, column);
+        if ret == 0 && !self.is_connected() {
+            Err(WootingError::Disconnected)
+        } else {
+            Ok(ret)
+        }
+    }
+
+    /// Read the analog value, represented by a `u8`, of pressed keys, up to a maximum of
+    /// `n` keys (maximum of sixteen). The scan index that `Backslash` and `ISO1` share is read
+    /// as the key that the reader's layout has.
+    ///
+    /// This function will return `Err(WootingError::InvalidBufferSize)` if `n` is zero or
+    /// larger than sixteen.
+    pub fn read_analog_keys<K: FromScanIndex>(&self, n: u8) -> Result<Vec<(K, u8)>, WootingError> {
+        if n == 0 || n > 16 {
+            return Err(WootingError::InvalidBufferSize);
+        }
+
+        let mut buffer: Vec<u8> = vec![0; n as usize * 2];
+        let read = self.backend.read_full_buffer(&mut buffer)?;
+        Ok(buffer
+            .chunks(2)
+            .take(read)
+            .filter_map(|chunk| match *chunk {
+                [scan_index, analog_value] => {
+                    K::from_scan_index_for(scan_index, self.layout).map(|key| (key, analog_value))
+                }
+                _ => unreachable!(),
+            })
+            .collect())
+    }
+
+    /// Read the analog value, represented by a `u8`, of every pressed key in the set, in matrix
+    /// order. Unlike `read_analog_keys`, there is no limit on how many pressed keys are read.
+    /// Keys that the keyboard model does not have are never pressed. Whether the keyboard is
+    /// connected is only checked once, if no key in the set is pressed.
+    ///
+    /// ```rust
+    /// # fn test() -> Result<(), wooting_sdk::WootingError> {
+    /// use wooting_sdk::{
+    ///     analog::AnalogReader, backend::SimulatedKeyboard, Key, KeySet, KeyboardModel,
+    ///     WootingError,
+    /// };
+    ///
+    /// let keyboard = SimulatedKeyboard::new();
+    /// let reader = AnalogReader::with_model(keyboard.clone(), KeyboardModel::WootingOne);
+    ///
+    /// keyboard.set_analog_value(Key::W, 200);
+    /// keyboard.set_analog_value(Key::K, 100);
+    /// keyboard.set_analog_value(Key::NumFive, 50);
+    ///
+    /// // Only keys in the set are read..
+    /// assert_eq!(reader.read_analog_keys_in(KeySet::WASD)?, vec![(Key::W, 200)]);
+    /// // ..and only if the model has them, as the Wooting One has no numpad.
+    /// assert_eq!(reader.read_analog_keys_in(KeySet::NUMPAD)?, vec![]);
+    ///
+    /// keyboard.set_connected(false);
+    /// assert_eq!(reader.read_analog_keys_in(KeySet::WASD), Err(WootingError::Disconnected));
+    /// # Ok(())
+    /// # }
+    /// # test().unwrap();
+    /// ```
+    pub fn read_analog_keys_in(&self, keys: KeySet) -> Result<Vec<(Key, u8)>, WootingError> {
+        let mut pressed = Vec::new();
+        for key in keys & self.model.keys().collect::<KeySet>() {
+            let (row, column) = key.into_matrix_row_and_column();
+            let value = self.backend.read_analog(row, column);
+            if value > 0 {
+                pressed.push((key, value));
+            }
+        }
+
+        // See `read_all_analog_keys`.
+        if pressed.is_empty() && !self.is_connected() {
+            Err(WootingError::Disconnected)
+        } else {
+            Ok(pressed)
+        }
+    }
+
+    /// Read the analog value, represented by a `u8`, of every key on the keyboard. Keys that the
+    /// keyboard model does not have are zero. Whether the keyboard is connected is only checked
+    /// once, if no key is pressed.
+    ///
+    /// ```rust
+    /// # fn test() -> Result<(), wooting_sdk::WootingError> {
+    /// use wooting_sdk::{
+    ///     analog::AnalogReader, backend::SimulatedKeyboard, Key, KeyboardModel, WootingError,
+    /// };
+    ///
+    /// let keyboard = SimulatedKeyboard::new();
+    /// let reader = AnalogReader::with_model(keyboard.clone(), KeyboardModel::WootingOne);
+    ///
+    /// keyboard.set_analog_value(Key::W, 200);
+    /// keyboard.set_analog_value(Key::NumFive, 100);
+    ///
+    /// let values = reader.read_all_analog_keys()?;
+    /// assert_eq!(values[Key::W], 200);
+    /// assert_eq!(values[Key::A], 0);
+    /// // The Wooting One has no numpad.
+    /// assert_eq!(values[Key::NumFive], 0);
+    ///
+    /// keyboard.set_connected(false);
+    /// assert_eq!(reader.read_all_analog_keys().err(), Some(WootingError::Disconnected));
+    /// # Ok(())
+    /// # }
+    /// # test().unwrap();
+    /// ```
+    pub fn read_all_analog_keys(&self) -> Result<KeyMap<u8>, WootingError> {
+        let mut values = KeyMap::new(0);
+        for key in self.model.keys() {
+            let (row, column) = key.into_matrix_row_and_column();
+            values[key] = self.backend.read_analog(row, column);
+        }
+
+        // A disconnected keyboard reads as zero for every key, which is only worth telling apart
+        // from no key being pressed when that is what was read.
+        if values.values().all(|&value| value == 0) && !self.is_connected() {
+            Err(WootingError::Disconnected)
+        } else {
+            Ok(values)
+        }
+    }
+}
+
+impl Default for AnalogReader {
+    fn default() -> Self {
+        AnalogReader::new(FfiBackend)
+    }
+}
@@ -0,0 +1,835 @@
+//! Contains the backends that analog reads and RGB writes are performed through.
+//!
+//! By default, [`AnalogReader`][analog] and [`RgbKeyboard`][rgb] use the [`FfiBackend`], which
+//! calls into Wooting's SDKs. The [`SimulatedKeyboard`] backend runs entirely in-process and can
+//! be used to test code that uses this crate without a physical keyboard.
+//!
+//! [analog]: ../analog/struct.AnalogReader.html
+//! [rgb]: ../rgb/struct.RgbKeyboard.html
+//! [`FfiBackend`]: struct.FfiBackend.html
+//! [`SimulatedKeyboard`]: struct.SimulatedKeyboard.html
+
+use super::{
+    FromMatrixRowColumn, IntoMatrixRowColumn, IntoScanIndex, Key, WootingError, COLUMNS,
+    COMPONENTS, ROWS,
+};
+
+#[cfg(any(feature = "analog", feature = "rgb"))]
+use std::cell::Cell;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+#[cfg(any(feature = "analog", feature = "rgb"))]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(any(feature = "analog", feature = "rgb"))]
+use std::sync::Once;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError, Weak};
+
+#[cfg(any(feature = "analog", feature = "rgb"))]
+use lazy_static::lazy_static;
+
+/// Types that implement this trait can perform the operations of Wooting's Analog SDK.
+pub trait AnalogBackend {
+    /// Is there a keyboard connected?
+    fn is_connected(&self) -> bool;
+
+    /// Subscribe a callback to be invoked when the keyboard is disconnected, until the returned
+    /// subscription is dropped.
+    fn subscribe_disconnected(&self, callback: Box<dyn Fn() + Send + Sync>) -> Subscription;
+
+    /// Read the analog value of the key at the given matrix row and column. Returns zero if
+    /// the key is not pressed or if the keyboard is disconnected.
+    fn read_analog(&self, row: u8, column: u8) -> u8;
+
+    /// Fill `buffer` with pairs of scan index and analog value for the keys that are currently
+    /// pressed. Returns the number of pairs that were written.
+    fn read_full_buffer(&self, buffer: &mut [u8]) -> Result<usize, WootingError>;
+}
+
+/// Types that implement this trait can perform the operations of Wooting's RGB SDK.
+pub trait RgbBackend {
+    /// Is there a keyboard connected?
+    fn is_connected(&self) -> bool;
+
+    /// Subscribe a callback to be invoked when the keyboard is disconnected, until the returned
+    /// subscription is dropped.
+    fn subscribe_disconnected(&self, callback: Box<dyn Fn() + Send + Sync>) -> Subscription;
+
+    /// Set the color of the key at the given matrix row and column, bypassing the color array.
+    fn direct_set_key(&self, row: u8, column: u8, red: u8, green: u8, blue: u8) -> bool;
+
+    /// Reset the color of the key at the given matrix row and column, bypassing the color array.
+    fn direct_reset_key(&self, row: u8, column: u8) -> bool;
+
+    /// Send the color array to the keyboard.
+    fn array_update_keyboard(&self) -> bool;
+
+    /// Set whether changes to the color array are sent to the keyboard immediately.
+    fn array_auto_update(&self, auto_update: bool);
+
+    /// Set the color of the key at the given matrix row and column in the color array.
+    fn array_set_single(&self, row: u8, column: u8, red: u8, green: u8, blue: u8) -> bool;
+
+    /// Replace the color array with a flattened array of colors, stored row by row.
+    fn array_set_full(&self, array: &[u8; COMPONENTS * COLUMNS * ROWS]) -> bool;
+
+    /// Restore all colors to those that were originally on the keyboard.
+    fn reset(&self) -> bool;
+
+    /// Claim exclusive control of the keyboard's LEDs within this process. Returns `false` if
+    /// they have already been claimed and not yet released.
+    fn try_claim(&self) -> bool;
+
+    /// Release a claim made with `try_claim`.
+    fn release(&self);
+}
+
+/// Types that implement this trait are backends that a [`Wooting`][wooting] context can own,
+/// which the context closes when it is dropped. A backend that has nothing to close can use the
+/// default `close`, which does nothing.
+///
+/// [wooting]: ../struct.Wooting.html
+pub trait ContextBackend {
+    /// Restore the keyboard to how it was before the SDKs were used, unless an `RgbKeyboard`
+    /// still has control of its LEDs (in which case the keyboard does so when it is dropped).
+    fn close(&self) {}
+}
+
+/// Restore the keyboard's original colors and turn off auto update, unless an `RgbKeyboard` has
+/// control of its LEDs.
+fn close_rgb<B: RgbBackend>(backend: &B) {
+    if backend.try_claim() {
+        let _ = backend.reset();
+        backend.array_auto_update(false);
+        backend.release();
+    }
+}
+
+/// Callback subscribed to a disconnect, shared so that it can be invoked without holding any
+/// lock. A callback can then be invoked again while it is running, such as when it uses the
+/// keyboard and that fails, or when two threads notice a disconnect at once.
+type Callback = Arc<dyn Fn() + Send + Sync>;
+
+/// Callbacks that are subscribed to the disconnect of a keyboard.
+#[derive(Default)]
+struct Subscribers {
+    next_id: u64,
+    callbacks: Vec<(u64, Callback)>,
+}
+
+/// Lock a mutex, even if a thread panicked while holding it. Subscribers and the state of a
+/// simulated keyboard are only changed in ways that cannot panic part of the way through, so they
+/// are never left inconsistent.
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Add a callback to the subscribers, returning the subscription that removes it when dropped.
+fn subscribe(
+    subscribers: &Arc<Mutex<Subscribers>>,
+    callback: Box<dyn Fn() + Send + Sync>,
+) -> Subscription {
+    let mut locked = lock(subscribers);
+    let id = locked.next_id;
+    locked.next_id += 1;
+    locked.callbacks.push((id, Arc::from(callback)));
+
+    Subscription {
+        subscribers: Arc::downgrade(subscribers),
+        id,
+    }
+}
+
+/// Invoke every subscribed callback. Callbacks are invoked without holding the lock on the
+/// subscribers, so that they can subscribe and unsubscribe, and a callback that panics does not
+/// stop the others from being invoked.
+fn notify(subscribers: &Mutex<Subscribers>) {
+    let callbacks: Vec<Callback> = lock(subscribers)
+        .callbacks
+        .iter()
+        .map(|(_, callback)| callback.clone())
+        .collect();
+
+    for callback in callbacks {
+        let _ = panic::catch_unwind(AssertUnwindSafe(&*callback));
+    }
+}
+
+/// Keeps a callback subscribed to the disconnect of a keyboard. The callback is unsubscribed
+/// when this is dropped.
+///
+/// ```rust
+/// # #[cfg(feature = "analog")] {
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+/// use wooting_sdk::{analog::AnalogReader, backend::SimulatedKeyboard};
+///
+/// let simulated = SimulatedKeyboard::new();
+/// let reader = AnalogReader::new(simulated.clone());
+///
+/// let count = Arc::new(AtomicUsize::new(0));
+/// let counter = count.clone();
+/// let subscription = reader.subscribe_disconnected(move || {
+///     let _ = counter.fetch_add(1, Ordering::SeqCst);
+/// });
+/// // A callback that panics does not stop the others from being invoked.
+/// let _panics = reader.subscribe_disconnected(|| panic!("callback panicked"));
+///
+/// simulated.set_connected(false);
+/// assert_eq!(count.load(Ordering::SeqCst), 1);
+///
+/// // Once the subscription is dropped, the callback is no longer invoked.
+/// drop(subscription);
+/// simulated.set_connected(true);
+/// simulated.set_connected(false);
+/// assert_eq!(count.load(Ordering::SeqCst), 1);
+/// # }
+/// ```
+#[must_use = "the callback is unsubscribed when the subscription is dropped"]
+pub struct Subscription {
+    subscribers: Weak<Mutex<Subscribers>>,
+    id: u64,
+}
+
+impl fmt::Debug for Subscription {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("Subscription")
+            .field("id", &self.id)
+            .finish()
+    }
+}
+
+impl Drop for Subscription {
+    fn drop(&mut self) {
+        if let Some(subscribers) = self.subscribers.upgrade() {
+            let id = self.id;
+            lock(&subscribers)
+                .callbacks
+                .retain(|&(other, _)| other != id);
+        }
+    }
+}
+
+#[cfg(any(feature = "analog", feature = "rgb"))]
+lazy_static! {
+    /// Held for every call into the SDKs, which are not thread-safe, so that a
+    /// `ConnectionWatcher` cannot call into them on its thread while the application does.
+    static ref SDK_LOCK: Mutex<()> = Mutex::new(());
+}
+
+#[cfg(any(feature = "analog", feature = "rgb"))]
+thread_local! {
+    /// Is this thread calling into the SDKs, and so holding `SDK_LOCK`?
+    static IN_SDK: Cell<bool> = Cell::new(false);
+}
+
+#[cfg(feature = "analog")]
+lazy_static! {
+    static ref ANALOG_SUBSCRIBERS: Arc<Mutex<Subscribers>> = Default::default();
+}
+
+#[cfg(feature = "rgb")]
+lazy_static! {
+    static ref RGB_SUBSCRIBERS: Arc<Mutex<Subscribers>> = Default::default();
+}
+
+/// Has the RGB SDK been claimed by an `RgbKeyboard`? The SDK controls a single keyboard per
+/// process, so there is only one claim for every `FfiBackend`.
+#[cfg(feature = "rgb")]
+static RGB_CLAIMED: AtomicBool = AtomicBool::new(false);
+
+/// Has the Analog SDK reported a disconnect that the subscribers have not been told about yet?
+#[cfg(feature = "analog")]
+static ANALOG_REPORTED: AtomicBool = AtomicBool::new(false);
+
+/// Has the RGB SDK reported a disconnect that the subscribers have not been told about yet?
+#[cfg(feature = "rgb")]
+static RGB_REPORTED: AtomicBool = AtomicBool::new(false);
+
+/// Is the analog trampoline function registered with the SDK?
+#[cfg(feature = "analog")]
+static ANALOG_REGISTERED: Once = Once::new();
+
+/// Is the RGB trampoline function registered with the SDK?
+#[cfg(feature = "rgb")]
+static RGB_REGISTERED: Once = Once::new();
+
+/// This is a trampoline function that is provided to the C function to be invoked which will
+/// in turn invoke the user provided callbacks. The user provided callbacks would normally be
+/// stored in userdata but due to the lack of any, we use a static instead. Panics must not
+/// unwind into the SDK, so they are caught here.
+#[cfg(feature = "analog")]
+extern "C" fn analog_disconnected_callback_handler() {
+    let _ = panic::catch_unwind(|| report_disconnect(&ANALOG_REPORTED, &ANALOG_SUBSCRIBERS));
+}
+
+/// This is a trampoline function that is provided to the C function to be invoked which will
+/// in turn invoke the user provided callbacks. The user provided callbacks would normally be
+/// stored in userdata but due to the lack of any, we use a static instead. Panics must not
+/// unwind into the SDK, so they are caught here.
+#[cfg(feature = "rgb")]
+extern "C" fn rgb_disconnected_callback_handler() {
+    let _ = panic::catch_unwind(|| report_disconnect(&RGB_REPORTED, &RGB_SUBSCRIBERS));
+}
+
+/// Invoke the subscribed callbacks for a disconnect that an SDK reported. If the SDK reported it
+/// during a call on this thread, the callbacks are invoked once `call_sdk` has released the lock
+/// on the SDKs instead, so that they can call into the SDKs too.
+#[cfg(any(feature = "analog", feature = "rgb"))]
+fn report_disconnect(reported: &AtomicBool, subscribers: &Mutex<Subscribers>) {
+    if IN_SDK.with(Cell::get) {
+        reported.store(true, Ordering::SeqCst);
+    } else {
+        notify(subscribers);
+    }
+}
+
+/// Invoke the subscribed callbacks if the SDK reported a disconnect during a call.
+#[cfg(any(feature = "analog", feature = "rgb"))]
+fn notify_reported(reported: &AtomicBool, subscribers: &Mutex<Subscribers>) {
+    if reported.swap(false, Ordering::SeqCst) {
+        notify(subscribers);
+    }
+}
+
+/// Call into the SDKs while holding the lock that every call into them shares.
+#[cfg(any(feature = "analog", feature = "rgb"))]
+fn call_sdk<T, F: FnOnce() -> T>(call: F) -> T {
+    let result = {
+        let _locked = lock(&SDK_LOCK);
+        IN_SDK.with(|in_sdk| in_sdk.set(true));
+        let result = call();
+        IN_SDK.with(|in_sdk| in_sdk.set(false));
+        result
+    };
+
+    #[cfg(feature = "analog")]
+    notify_reported(&ANALOG_REPORTED, &ANALOG_SUBSCRIBERS);
+    #[cfg(feature = "rgb")]
+    notify_reported(&RGB_REPORTED, &RGB_SUBSCRIBERS);
+    result
+}
+
+/// Backend that calls into Wooting's Analog and RGB SDKs. This is the default backend.
+///
+/// The analog operations are only available with the `analog` feature and the RGB operations
+/// are only available with the `rgb` feature.
+///
+/// The SDKs are not thread-safe, so calls into them are made one at a time, whichever thread
+/// they are made from. Disconnected callbacks are invoked once the call that noticed the
+/// disconnect has returned, so that they can use the SDKs too.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct FfiBackend;
+
+#[cfg(feature = "analog")]
+impl AnalogBackend for FfiBackend {
+    fn is_connected(&self) -> bool {
+        call_sdk(|| unsafe { wooting_analog_sdk_sys::wooting_kbd_connected() })
+    }
+
+    fn subscribe_disconnected(&self, callback: Box<dyn Fn() + Send + Sync>) -> Subscription {
+        let subscription = subscribe(&ANALOG_SUBSCRIBERS, callback);
+        // The SDK is only called once the lock on the subscribers has been released, as it may
+        // invoke the trampoline function straight away.
+        ANALOG_REGISTERED.call_once(|| {
+            call_sdk(|| unsafe {
+                wooting_analog_sdk_sys::wooting_set_disconnected_cb(Some(
+                    analog_disconnected_callback_handler,
+                ));
+            })
+        });
+        subscription
+    }
+
+    fn read_analog(&self, row: u8, column: u8) -> u8 {
+        call_sdk(|| unsafe { wooting_analog_sdk_sys::wooting_read_analog(row, column) })
+    }
+
+    fn read_full_buffer(&self, buffer: &mut [u8]) -> Result<usize, WootingError> {
+        let ret: i32 = call_sdk(|| unsafe {
+            wooting_analog_sdk_sys::wooting_read_full_buffer(
+                buffer.as_mut_ptr(),
+                buffer.len() as u32,
+            )
+        });
+
+        match ret {
+            -1 => Err(WootingError::Disconnected),
+            ret if ret < -1 => panic!("Invalid return code from Wooting Analog SDK"),
+            ret => Ok(ret as usize),
+        }
+    }
+}
+
+#[cfg(feature = "rgb")]
+impl RgbBackend for FfiBackend {
+    fn is_connected(&self) -> bool {
+        call_sdk(|| unsafe { wooting_rgb_sdk_sys::wooting_rgb_kbd_connected() })
+    }
+
+    fn subscribe_disconnected(&self, callback: Box<dyn Fn() + Send + Sync>) -> Subscription {
+        let subscription = subscribe(&RGB_SUBSCRIBERS, callback);
+        // The SDK is only called once the lock on the subscribers has been released, as it may
+        // invoke the trampoline function straight away.
+        RGB_REGISTERED.call_once(|| {
+            call_sdk(|| unsafe {
+                wooting_rgb_sdk_sys::wooting_rgb_set_disconnected_cb(Some(
+                    rgb_disconnected_callback_handler,
+                ));
+            })
+        });
+        subscription
+    }
+
+    fn direct_set_key(&self, row: u8, column: u8, red: u8, green: u8, blue: u8) -> bool {
+        call_sdk(|| unsafe {
+            wooting_rgb_sdk_sys::wooting_rgb_direct_set_key(row, column, red, green, blue)
+        })
+    }
+
+    fn direct_reset_key(&self, row: u8, column: u8) -> bool {
+        call_sdk(|| unsafe { wooting_rgb_sdk_sys::wooting_rgb_direct_reset_key(row, column) })
+    }
+
+    fn array_update_keyboard(&self) -> bool {
+        call_sdk(|| unsafe { wooting_rgb_sdk_sys::wooting_rgb_array_update_keyboard() })
+    }
+
+    fn array_auto_update(&self, auto_update: bool) {
+        call_sdk(|| unsafe { wooting_rgb_sdk_sys::wooting_rgb_array_auto_update(auto_update) })
+    }
+
+    fn array_set_single(&self, row: u8, column: u8, red: u8, green: u8, blue: u8) -> bool {
+        call_sdk(|| unsafe {
+            wooting_rgb_sdk_sys::wooting_rgb_array_set_single(row, column, red, green, blue)
+        })
+    }
+
+    fn array_set_full(&self, array: &[u8; COMPONENTS * COLUMNS * ROWS]) -> bool {
+        call_sdk(|| unsafe { wooting_rgb_sdk_sys::wooting_rgb_array_set_full(array.as_ptr()) })
+    }
+
+    fn reset(&self) -> bool {
+        call_sdk(|| unsafe { wooting_rgb_sdk_sys::wooting_rgb_reset() })
+    }
+
+    fn try_claim(&self) -> bool {
+        !RGB_CLAIMED.swap(true, Ordering::SeqCst)
+    }
+
+    fn release(&self) {
+        RGB_CLAIMED.store(false, Ordering::SeqCst);
+    }
+}
+
+impl ContextBackend for FfiBackend {
+    fn close(&self) {
+        #[cfg(feature = "rgb")]
+        close_rgb(self);
+    }
+}
+
+/// Colors displayed by a [`SimulatedKeyboard`] after a write, indexed by matrix row and column.
+/// Keys that show the color they had originally (before any write, or after being reset) are
+/// `None`.
+///
+/// [`SimulatedKeyboard`]: struct.SimulatedKeyboard.html
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SimulatedFrame {
+    colors: [[Option<(u8, u8, u8)>; COLUMNS]; ROWS],
+}
+
+impl SimulatedFrame {
+    /// Return the color that the key is showing, or `None` if it shows its original color.
+    pub fn color<K: IntoMatrixRowColumn>(&self, key: K) -> Option<(u8, u8, u8)> {
+        let (row, column) = key.into_matrix_row_and_column();
+        self.colors
+            .get(row as usize)
+            .and_then(|row| row.get(column as usize))
+            .and_then(|color| *color)
+    }
+
+    /// Return every key that is not showing its original color, along with its color, in
+    /// matrix order.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "rgb")] {
+    /// use wooting_sdk::{backend::SimulatedKeyboard, rgb::RgbKeyboard, Key};
+    ///
+    /// let simulated = SimulatedKeyboard::new();
+    /// let mut keyboard = RgbKeyboard::new(simulated.clone()).unwrap();
+    /// keyboard.direct_set_key(Key::B, (0, 0, 255)).unwrap();
+    /// keyboard.direct_set_key(Key::A, (255, 0, 0)).unwrap();
+    ///
+    /// assert_eq!(
+    ///     simulated.displayed().colors::<Key>(),
+    ///     vec![(Key::A, (255, 0, 0)), (Key::B, (0, 0, 255))]
+    /// );
+    /// # }
+    /// ```
+    pub fn colors<K: FromMatrixRowColumn>(&self) -> Vec<(K, (u8, u8, u8))> {
+        self.colors
+            .iter()
+            .enumerate()
+            .flat_map(|(row, colors)| {
+                colors
+                    .iter()
+                    .enumerate()
+                    .filter_map(move |(column, color)| color.map(|color| (row, column, color)))
+            })
+            .filter_map(|(row, column, color)| {
+                K::from_matrix_row_and_column(row as u8, column as u8).map(|key| (key, color))
+            })
+            .collect()
+    }
+}
+
+/// State shared between all clones of a `SimulatedKeyboard`.
+struct SimulatedState {
+    connected: bool,
+    analog: [[u8; COLUMNS]; ROWS],
+    auto_update: bool,
+    writes_fail: bool,
+    rgb_claimed: bool,
+    array: [[(u8, u8, u8); COLUMNS]; ROWS],
+    displayed: SimulatedFrame,
+    frames: Vec<SimulatedFrame>,
+}
+
+impl SimulatedState {
+    /// Can colors be written to the keyboard's LEDs?
+    fn writable(&self) -> bool {
+        self.connected && !self.writes_fail
+    }
+
+    /// Record the currently displayed colors as a frame that was written to the keyboard.
+    fn push_frame(&mut self) {
+        let frame = self.displayed;
+        self.frames.push(frame);
+    }
+
+    /// Copy the color array to the displayed colors, as when the array is sent to the keyboard.
+    fn update_from_array(&mut self) {
+        for (displayed, array) in self.displayed.colors.iter_mut().zip(self.array.iter()) {
+            for (displayed, color) in displayed.iter_mut().zip(array.iter()) {
+                *displayed = Some(*color);
+            }
+        }
+        self.push_frame();
+    }
+}
+
+impl fmt::Debug for SimulatedState {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SimulatedState")
+            .field("connected", &self.connected)
+            .field("auto_update", &self.auto_update)
+            .field("writes_fail", &self.writes_fail)
+            .field("rgb_claimed", &self.rgb_claimed)
+            .field("displayed", &self.displayed)
+            .field("frames", &self.frames.len())
+            .finish()
+    }
+}
+
+/// Backend that simulates a keyboard in-process. Analog values and the connection state can be
+/// scripted, and every frame written to the keyboard's LEDs is recorded so that it can be
+/// inspected.
+///
+/// Clones of a `SimulatedKeyboard` share the same state, so a test can hand one clone to an
+/// [`AnalogReader`][analog] or [`RgbKeyboard`][rgb] and keep another to drive and inspect the
+/// simulation.
+///
+/// ```rust
+/// # #[cfg(feature = "analog")] {
+/// use wooting_sdk::{analog::AnalogReader, backend::SimulatedKeyboard, Key, WootingError};
+///
+/// let keyboard = SimulatedKeyboard::new();
+/// let reader = AnalogReader::new(keyboard.clone());
+///
+/// keyboard.set_analog_value(Key::W, 128);
+/// assert_eq!(reader.read_analog_key(Key::W), Ok(128));
+///
+/// keyboard.set_connected(false);
+/// assert_eq!(reader.read_analog_key(Key::W), Err(WootingError::Disconnected));
+/// # }
+/// ```
+///
+/// [analog]: ../analog/struct.AnalogReader.html
+/// [rgb]: ../rgb/struct.RgbKeyboard.html
+#[derive(Clone)]
+pub struct SimulatedKeyboard {
+    state: Arc<Mutex<SimulatedState>>,
+    analog_subscribers: Arc<Mutex<Subscribers>>,
+    rgb_subscribers: Arc<Mutex<Subscribers>>,
+}
+
+impl SimulatedKeyboard {
+    /// Create a new simulated keyboard that is connected, has no keys pressed and shows its
+    /// original colors.
+    pub fn new() -> Self {
+        SimulatedKeyboard {
+            state: Arc::new(Mutex::new(SimulatedState {
+                connected: true,
+                analog: [[0; COLUMNS]; ROWS],
+                auto_update: false,
+                writes_fail: false,
+                rgb_claimed: false,
+                array: [[(0, 0, 0); COLUMNS]; ROWS],
+                displayed: SimulatedFrame::default(),
+                frames: Vec::new(),
+            })),
+            analog_subscribers: Default::default(),
+            rgb_subscribers: Default::default(),
+        }
+    }
+
+    /// Is the simulated keyboard connected?
+    pub fn is_connected(&self) -> bool {
+        lock(&self.state).connected
+    }
+
+    /// Connect or disconnect the simulated keyboard. Disconnecting invokes any disconnected
+    /// callbacks. Reconnecting restores the original colors of every key, as a physical keyboard
+    /// would when plugged back in.
+    pub fn set_connected(&self, connected: bool) {
+        let disconnected = {
+            let mut state = lock(&self.state);
+            let was_connected = state.connected;
+            state.connected = connected;
+
+            if connected && !was_connected {
+                state.displayed = SimulatedFrame::default();
+            }
+
+            !connected && was_connected
+        };
+
+        // Callbacks are invoked without holding the lock so that they can use the keyboard.
+        if disconnected {
+            notify(&self.analog_subscribers);
+            notify(&self.rgb_subscribers);
+        }
+    }
+
+    /// Make every write to the LEDs of the simulated keyboard fail while it stays connected, as
+    /// when a USB transfer fails.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "rgb")] {
+    /// use wooting_sdk::{backend::SimulatedKeyboard, rgb::RgbKeyboard, Key, WootingError};
+    ///
+    /// let simulated = SimulatedKeyboard::new();
+    /// let mut keyboard = RgbKeyboard::new(simulated.clone()).unwrap();
+    ///
+    /// simulated.set_writes_fail(true);
+    /// assert_eq!(
+    ///     keyboard.direct_set_key(Key::A, (255, 0, 0)),
+    ///     Err(WootingError::WriteFailed)
+    /// );
+    ///
+    /// simulated.set_writes_fail(false);
+    /// assert_eq!(keyboard.direct_set_key(Key::A, (255, 0, 0)), Ok(()));
+    /// # }
+    /// ```
+    pub fn set_writes_fail(&self, writes_fail: bool) {
+        lock(&self.state).writes_fail = writes_fail;
+    }
+
+    /// Set the analog value of a key, where zero is not pressed and `u8::max_value()` is
+    /// completely pressed.
+    pub fn set_analog_value<K: IntoMatrixRowColumn>(&self, key: K, value: u8) {
+        let (row, column) = key.into_matrix_row_and_column();
+        lock(&self.state).analog[row as usize][column as usize] = value;
+    }
+
+    /// Release every key.
+    pub fn release_all(&self) {
+        lock(&self.state).analog = [[0; COLUMNS]; ROWS];
+    }
+
+    /// Return the colors that the keyboard is currently showing.
+    pub fn displayed(&self) -> SimulatedFrame {
+        lock(&self.state).displayed
+    }
+
+    /// Return every frame that has been written to the keyboard, oldest first. A frame is
+    /// recorded for each direct write, each update of the color array and each reset.
+    pub fn frames(&self) -> Vec<SimulatedFrame> {
+        lock(&self.state).frames.clone()
+    }
+
+    /// Forget every frame that has been recorded.
+    pub fn clear_frames(&self) {
+        lock(&self.state).frames.clear();
+    }
+}
+
+impl fmt::Debug for SimulatedKeyboard {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_struct("SimulatedKeyboard")
+            .field("state", &self.state)
+            .finish()
+    }
+}
+
+impl Default for SimulatedKeyboard {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ContextBackend for SimulatedKeyboard {
+    fn close(&self) {
+        close_rgb(self);
+    }
+}
+
+impl AnalogBackend for SimulatedKeyboard {
+    fn is_connected(&self) -> bool {
+        SimulatedKeyboard::is_connected(self)
+    }
+
+    fn subscribe_disconnected(&self, callback: Box<dyn Fn() + Send + Sync>) -> Subscription {
+        subscribe(&self.analog_subscribers, callback)
+    }
+
+    fn read_analog(&self, row: u8, column: u8) -> u8 {
+        let state = lock(&self.state);
+        if !state.connected {
+            return 0;
+        }
+
+        state
+            .analog
+            .get(row as usize)
+            .and_then(|row| row.get(column as usize))
+            .cloned()
+            .unwrap_or(0)
+    }
+
+    fn read_full_buffer(&self, buffer: &mut [u8]) -> Result<usize, WootingError> {
+        let state = lock(&self.state);
+        if !state.connected {
+            return Err(WootingError::Disconnected);
+        }
+
+        let pressed = Key::iter_scan_order().filter_map(|key| {
+            let (row, column) = key.into_matrix_row_and_column();
+            match state.analog[row as usize][column as usize] {
+                0 => None,
+                value => Some((key.into_scan_index(), value)),
+            }
+        });
+
+        let mut written = 0;
+        for (chunk, (scan_index, value)) in buffer.chunks_mut(2).zip(pressed) {
+            if let [index_slot, value_slot] = chunk {
+                *index_slot = scan_index;
+                *value_slot = value;
+                written += 1;
+            }
+        }
+        Ok(written)
+    }
+}
+
+impl RgbBackend for SimulatedKeyboard {
+    fn is_connected(&self) -> bool {
+        SimulatedKeyboard::is_connected(self)
+    }
+
+    fn subscribe_disconnected(&self, callback: Box<dyn Fn() + Send + Sync>) -> Subscription {
+        subscribe(&self.rgb_subscribers, callback)
+    }
+
+    fn direct_set_key(&self, row: u8, column: u8, red: u8, green: u8, blue: u8) -> bool {
+        let mut state = lock(&self.state);
+        if !state.writable() || row as usize >= ROWS || column as usize >= COLUMNS {
+            return false;
+        }
+
+        state.displayed.colors[row as usize][column as usize] = Some((red, green, blue));
+        state.push_frame();
+        true
+    }
+
+    fn direct_reset_key(&self, row: u8, column: u8) -> bool {
+        let mut state = lock(&self.state);
+        if !state.writable() || row as usize >= ROWS || column as usize >= COLUMNS {
+            return false;
+        }
+
+        state.displayed.colors[row as usize][column as usize] = None;
+        state.push_frame();
+        true
+    }
+
+    fn array_update_keyboard(&self) -> bool {
+        let mut state = lock(&self.state);
+        if !state.writable() {
+            return false;
+        }
+
+        state.update_from_array();
+        true
+    }
+
+    fn array_auto_update(&self, auto_update: bool) {
+        lock(&self.state).auto_update = auto_update;
+    }
+
+    fn array_set_single(&self, row: u8, column: u8, red: u8, green: u8, blue: u8) -> bool {
+        let mut state = lock(&self.state);
+        if !state.connected
+            || (state.auto_update && state.writes_fail)
+            || row as usize >= ROWS
+            || column as usize >= COLUMNS
+        {
+            return false;
+        }
+
+        state.array[row as usize][column as usize] = (red, green, blue);
+        if state.auto_update {
+            state.update_from_array();
+        }
+        true
+    }
+
+    fn array_set_full(&self, array: &[u8; COMPONENTS * COLUMNS * ROWS]) -> bool {
+        let mut state = lock(&self.state);
+        if !state.connected || (state.auto_update && state.writes_fail) {
+            return false;
+        }
+
+        for (row, colors) in array.chunks(COLUMNS * COMPONENTS).enumerate() {
+            for (column, color) in colors.chunks(COMPONENTS).enumerate() {
+                state.array[row][column] = (color[0], color[1], color[2]);
+            }
+        }
+        if state.auto_update {
+            state.update_from_array();
+        }
+        true
+    }
+
+    fn reset(&self) -> bool {
+        let mut state = lock(&self.state);
+        if !state.writable() {
+            return false;
+        }
+
+        state.displayed = SimulatedFrame::default();
+        state.push_frame();
+        true
+    }
+
+    fn try_claim(&self) -> bool {
+        let mut state = lock(&self.state);
+        !std::mem::replace(&mut state.rgb_claimed, true)
+    }
+
+    fn release(&self) {
+        lock(&self.state).rgb_claimed = false;
+    }
+}
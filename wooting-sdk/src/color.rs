@@ -0,0 +1,412 @@
+//! Contains the color of a key, along with conversions between color spaces.
+
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Represents the color of a key, as the red, green and blue components that the keyboard's LEDs
+/// show.
+///
+/// Colors convert from and to `(red, green, blue)` tuples, so every method that takes a
+/// `impl Into<Color>` also takes a tuple, and are parsed from and formatted as `#rrggbb`.
+///
+/// ```rust
+/// use wooting_sdk::rgb::Color;
+///
+/// let orange: Color = "#ff8000".parse().unwrap();
+/// assert_eq!(orange, Color::new(255, 128, 0));
+/// assert_eq!(orange, Color::from((255, 128, 0)));
+/// assert_eq!(orange.to_string(), "#ff8000");
+///
+/// let (red, green, blue) = orange.into();
+/// assert_eq!((red, green, blue), (255, 128, 0));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(C)]
+pub struct Color {
+    /// Red component.
+    pub red: u8,
+    /// Green component.
+    pub green: u8,
+    /// Blue component.
+    pub blue: u8,
+}
+
+impl Color {
+    /// No light.
+    pub const BLACK: Color = Color::new(0, 0, 0);
+    /// Every component at full brightness.
+    pub const WHITE: Color = Color::new(255, 255, 255);
+    /// Only red at full brightness.
+    pub const RED: Color = Color::new(255, 0, 0);
+    /// Only green at full brightness.
+    pub const GREEN: Color = Color::new(0, 255, 0);
+    /// Only blue at full brightness.
+    pub const BLUE: Color = Color::new(0, 0, 255);
+
+    /// Create a color from its red, green and blue components.
+    pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+        Color { red, green, blue }
+    }
+
+    /// Create a color from its hue (in degrees), saturation and value (both from zero to one).
+    /// Hues outside of `0.0..360.0` wrap around (and hues that are not finite are zero), and
+    /// saturations and values outside of `0.0..=1.0` are clamped.
+    ///
+    /// ```rust
+    /// use wooting_sdk::rgb::Color;
+    ///
+    /// assert_eq!(Color::from_hsv(0.0, 1.0, 1.0), Color::RED);
+    /// assert_eq!(Color::from_hsv(480.0, 1.0, 1.0), Color::GREEN);
+    /// assert_eq!(Color::from_hsv(30.0, 1.0, 1.0), Color::new(255, 128, 0));
+    /// assert_eq!(Color::from_hsv(0.0, 0.0, 0.5), Color::new(128, 128, 128));
+    /// assert_eq!(Color::from_hsv(f32::NAN, 1.0, 1.0), Color::RED);
+    ///
+    /// // Every color converts to HSV and back again.
+    /// for &color in &[Color::BLACK, Color::WHITE, Color::new(12, 200, 99)] {
+    ///     let (hue, saturation, value) = color.to_hsv();
+    ///     assert_eq!(Color::from_hsv(hue, saturation, value), color);
+    /// }
+    /// ```
+    pub fn from_hsv(hue: f32, saturation: f32, value: f32) -> Self {
+        let saturation = clamp_unit(saturation);
+        let value = clamp_unit(value);
+        let chroma = value * saturation;
+        Color::from_hue_chroma(hue, chroma, value - chroma)
+    }
+
+    /// Return the hue (in degrees), saturation and value (both from zero to one) of the color.
+    /// The hue of grays is zero.
+    pub fn to_hsv(self) -> (f32, f32, f32) {
+        let (hue, max, min) = self.hue_max_min();
+        let saturation = if max == 0.0 { 0.0 } else { (max - min) / max };
+        (hue, saturation, max)
+    }
+
+    /// Create a color from its hue (in degrees), saturation and lightness (both from zero to
+    /// one). Hues outside of `0.0..360.0` wrap around (and hues that are not finite are zero), and
+    /// saturations and lightnesses outside of `0.0..=1.0` are clamped.
+    ///
+    /// ```rust
+    /// use wooting_sdk::rgb::Color;
+    ///
+    /// assert_eq!(Color::from_hsl(240.0, 1.0, 0.5), Color::BLUE);
+    /// assert_eq!(Color::from_hsl(0.0, 1.0, 1.0), Color::WHITE);
+    /// assert_eq!(Color::from_hsl(0.0, 1.0, 0.25), Color::new(128, 0, 0));
+    /// assert_eq!(Color::from_hsl(f32::INFINITY, 1.0, 0.5), Color::RED);
+    ///
+    /// // Every color converts to HSL and back again.
+    /// for &color in &[Color::BLACK, Color::WHITE, Color::new(12, 200, 99)] {
+    ///     let (hue, saturation, lightness) = color.to_hsl();
+    ///     assert_eq!(Color::from_hsl(hue, saturation, lightness), color);
+    /// }
+    /// ```
+    pub fn from_hsl(hue: f32, saturation: f32, lightness: f32) -> Self {
+        let saturation = clamp_unit(saturation);
+        let lightness = clamp_unit(lightness);
+        let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+        Color::from_hue_chroma(hue, chroma, lightness - chroma / 2.0)
+    }
+
+    /// Return the hue (in degrees), saturation and lightness (both from zero to one) of the
+    /// color. The hue of grays is zero.
+    pub fn to_hsl(self) -> (f32, f32, f32) {
+        let (hue, max, min) = self.hue_max_min();
+        let lightness = (max + min) / 2.0;
+        let saturation = if max - min <= 0.0 {
+            0.0
+        } else {
+            (max - min) / (1.0 - (2.0 * lightness - 1.0).abs())
+        };
+        (hue, saturation, lightness)
+    }
+
+    /// Create a color from its OKLCH lightness (from zero to one), chroma (from zero, with the
+    /// most saturated colors at around `0.37`) and hue (in degrees). OKLCH is perceptually
+    /// uniform, so colors with the same lightness look equally bright, which makes it well
+    /// suited to gradients. Colors that the keyboard cannot show are clamped.
+    ///
+    /// ```rust
+    /// use wooting_sdk::rgb::Color;
+    ///
+    /// assert_eq!(Color::from_oklch(1.0, 0.0, 0.0), Color::WHITE);
+    /// assert_eq!(Color::from_oklch(0.0, 0.0, 0.0), Color::BLACK);
+    /// assert_eq!(Color::from_oklch(0.6280, 0.2577, 29.23), Color::RED);
+    ///
+    /// let (lightness, chroma, hue) = Color::BLUE.to_oklch();
+    /// assert!((lightness - 0.4520).abs() < 0.001);
+    /// assert!((chroma - 0.3132).abs() < 0.001);
+    /// assert!((hue - 264.05).abs() < 0.01);
+    ///
+    /// // Every color converts to OKLCH and back again.
+    /// for &color in &[Color::BLACK, Color::WHITE, Color::new(12, 200, 99)] {
+    ///     let (lightness, chroma, hue) = color.to_oklch();
+    ///     assert_eq!(Color::from_oklch(lightness, chroma, hue), color);
+    /// }
+    /// ```
+    pub fn from_oklch(lightness: f32, chroma: f32, hue: f32) -> Self {
+        let lightness = f64::from(lightness);
+        let chroma = f64::from(chroma.max(0.0));
+        let hue = f64::from(hue).to_radians();
+        // The `a` (green to red) and `b` (blue to yellow) axes of OKLab.
+        let (green_red, blue_yellow) = (chroma * hue.cos(), chroma * hue.sin());
+
+        // Responses of the long, medium and short cones.
+        let long =
+            (lightness + 0.396_337_777_4 * green_red + 0.215_803_757_3 * blue_yellow).powi(3);
+        let medium =
+            (lightness - 0.105_561_345_8 * green_red - 0.063_854_172_8 * blue_yellow).powi(3);
+        let short =
+            (lightness - 0.089_484_177_5 * green_red - 1.291_485_548_0 * blue_yellow).powi(3);
+
+        Color::from_linear(
+            4.076_741_662_1 * long - 3.307_711_591_3 * medium + 0.230_969_929_2 * short,
+            -1.268_438_004_6 * long + 2.609_757_401_1 * medium - 0.341_319_396_5 * short,
+            -0.004_196_086_3 * long - 0.703_418_614_7 * medium + 1.707_614_701_0 * short,
+        )
+    }
+
+    /// Return the OKLCH lightness (from zero to one), chroma and hue (in degrees) of the color.
+    /// The hue of grays is zero.
+    pub fn to_oklch(self) -> (f32, f32, f32) {
+        let (red, green, blue) = (
+            to_linear(self.red),
+            to_linear(self.green),
+            to_linear(self.blue),
+        );
+
+        // Responses of the long, medium and short cones.
+        let long =
+            (0.412_221_470_8 * red + 0.536_332_536_3 * green + 0.051_445_992_9 * blue).cbrt();
+        let medium =
+            (0.211_903_498_2 * red + 0.680_699_545_1 * green + 0.107_396_956_6 * blue).cbrt();
+        let short =
+            (0.088_302_461_9 * red + 0.281_718_837_6 * green + 0.629_978_700_5 * blue).cbrt();
+
+        let lightness = 0.210_454_255_3 * long + 0.793_617_785_0 * medium - 0.004_072_046_8 * short;
+        let green_red = 1.977_998_495_1 * long - 2.428_592_205_0 * medium + 0.450_593_709_9 * short;
+        let blue_yellow =
+            0.025_904_037_1 * long + 0.782_771_766_2 * medium - 0.808_675_766_0 * short;
+
+        let chroma = green_red.hypot(blue_yellow);
+        // Grays have no hue, but rounding leaves a tiny chroma with an arbitrary one.
+        let hue = if chroma < 1e-4 {
+            0.0
+        } else {
+            blue_yellow.atan2(green_red).to_degrees().rem_euclid(360.0)
+        };
+        (lightness as f32, chroma as f32, hue as f32)
+    }
+
+    /// Return the color that is `t` of the way from this color to the other, where `0.0` is this
+    /// color and `1.0` is the other. `t` is clamped to `0.0..=1.0`.
+    ///
+    /// ```rust
+    /// use wooting_sdk::rgb::Color;
+    ///
+    /// assert_eq!(Color::BLACK.lerp(Color::WHITE, 0.5), Color::new(128, 128, 128));
+    /// assert_eq!(Color::RED.lerp(Color::BLUE, 0.25), Color::new(191, 0, 64));
+    /// assert_eq!(Color::RED.lerp(Color::BLUE, 2.0), Color::BLUE);
+    /// ```
+    pub fn lerp(self, other: Color, t: f32) -> Color {
+        let t = clamp_unit(t);
+        let lerp = |from: u8, to: u8| {
+            let (from, to) = (f32::from(from), f32::from(to));
+            to_component(from + (to - from) * t)
+        };
+        Color::new(
+            lerp(self.red, other.red),
+            lerp(self.green, other.green),
+            lerp(self.blue, other.blue),
+        )
+    }
+
+    /// Return the color of drawing the other color over this one with the given alpha, where
+    /// zero leaves this color unchanged and `255` replaces it.
+    ///
+    /// ```rust
+    /// use wooting_sdk::rgb::Color;
+    ///
+    /// assert_eq!(Color::BLUE.blend(Color::RED, 0), Color::BLUE);
+    /// assert_eq!(Color::BLUE.blend(Color::RED, 255), Color::RED);
+    /// assert_eq!(Color::BLACK.blend(Color::WHITE, 64), Color::new(64, 64, 64));
+    /// ```
+    pub fn blend(self, over: Color, alpha: u8) -> Color {
+        let alpha = u16::from(alpha);
+        let blend = |under: u8, over: u8| {
+            let mixed = u16::from(under) * (255 - alpha) + u16::from(over) * alpha;
+            // Divide by 255, rounding to the nearest integer.
+            ((mixed + 127) / 255) as u8
+        };
+        Color::new(
+            blend(self.red, over.red),
+            blend(self.green, over.green),
+            blend(self.blue, over.blue),
+        )
+    }
+
+    /// Create a color from a hue (in degrees), the chroma and the amount that is added to every
+    /// component, as shared by HSV and HSL.
+    fn from_hue_chroma(hue: f32, chroma: f32, offset: f32) -> Self {
+        // A hue that is not finite has no sector, and casting NaN to an integer is undefined.
+        let hue = if hue.is_finite() { hue } else { 0.0 };
+        let sector = hue.rem_euclid(360.0) / 60.0;
+        let second = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+        let (red, green, blue) = match sector as u8 {
+            0 => (chroma, second, 0.0),
+            1 => (second, chroma, 0.0),
+            2 => (0.0, chroma, second),
+            3 => (0.0, second, chroma),
+            4 => (second, 0.0, chroma),
+            _ => (chroma, 0.0, second),
+        };
+        Color::new(
+            to_component((red + offset) * 255.0),
+            to_component((green + offset) * 255.0),
+            to_component((blue + offset) * 255.0),
+        )
+    }
+
+    /// Return the hue (in degrees) and the largest and smallest components (from zero to one) of
+    /// the color, as shared by HSV and HSL.
+    fn hue_max_min(self) -> (f32, f32, f32) {
+        let max = self.red.max(self.green).max(self.blue);
+        let min = self.red.min(self.green).min(self.blue);
+
+        let (red, green, blue) = (
+            f32::from(self.red),
+            f32::from(self.green),
+            f32::from(self.blue),
+        );
+        let delta = f32::from(max - min);
+        let hue = if max == min {
+            0.0
+        } else if max == self.red {
+            60.0 * ((green - blue) / delta).rem_euclid(6.0)
+        } else if max == self.green {
+            60.0 * ((blue - red) / delta + 2.0)
+        } else {
+            60.0 * ((red - green) / delta + 4.0)
+        };
+        (hue, f32::from(max) / 255.0, f32::from(min) / 255.0)
+    }
+
+    /// Create a color from linear (not gamma encoded) sRGB components, from zero to one.
+    fn from_linear(red: f64, green: f64, blue: f64) -> Self {
+        let encode = |component: f64| {
+            let encoded = if component <= 0.003_130_8 {
+                12.92 * component
+            } else {
+                1.055 * component.powf(1.0 / 2.4) - 0.055
+            };
+            to_component(encoded as f32 * 255.0)
+        };
+        Color::new(encode(red), encode(green), encode(blue))
+    }
+}
+
+/// Clamp a value to `0.0..=1.0`.
+fn clamp_unit(value: f32) -> f32 {
+    value.max(0.0).min(1.0)
+}
+
+/// Return a component from zero to `255.0` as the nearest `u8`, clamping components that are out
+/// of range.
+fn to_component(value: f32) -> u8 {
+    value.round().max(0.0).min(255.0) as u8
+}
+
+/// Return the linear (not gamma encoded) value of a sRGB component, from zero to one.
+fn to_linear(component: u8) -> f64 {
+    let component = f64::from(component) / 255.0;
+    if component <= 0.040_45 {
+        component / 12.92
+    } else {
+        ((component + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((red, green, blue): (u8, u8, u8)) -> Self {
+        Color::new(red, green, blue)
+    }
+}
+
+impl From<Color> for (u8, u8, u8) {
+    fn from(color: Color) -> Self {
+        (color.red, color.green, color.blue)
+    }
+}
+
+impl Display for Color {
+    /// Formats the color as `#rrggbb`.
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "#{:02x}{:02x}{:02x}", self.red, self.green, self.blue)
+    }
+}
+
+impl FromStr for Color {
+    type Err = ParseColorError;
+
+    /// Parse a color from `#rrggbb`, in either case.
+    ///
+    /// ```rust
+    /// use wooting_sdk::rgb::Color;
+    ///
+    /// assert_eq!("#00FF7f".parse(), Ok(Color::new(0, 255, 127)));
+    /// assert!("00ff7f".parse::<Color>().is_err());
+    /// assert!("#00ff7".parse::<Color>().is_err());
+    /// assert!("#00ff7g".parse::<Color>().is_err());
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let error = || ParseColorError {
+            input: input.to_owned(),
+        };
+
+        if !input.starts_with('#') {
+            return Err(error());
+        }
+
+        let hex = &input[1..];
+        if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(error());
+        }
+
+        let component = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16);
+        match (component(0), component(2), component(4)) {
+            (Ok(red), Ok(green), Ok(blue)) => Ok(Color::new(red, green, blue)),
+            _ => Err(error()),
+        }
+    }
+}
+
+/// Represents an error that can occur when parsing a `Color` from a string that is not
+/// `#rrggbb`.
+///
+/// ```rust
+/// use wooting_sdk::rgb::Color;
+///
+/// let error = "red".parse::<Color>().unwrap_err();
+/// assert_eq!(error.input(), "red");
+/// assert_eq!(error.to_string(), "invalid color `red`, expected `#rrggbb`");
+/// ```
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct ParseColorError {
+    input: String,
+}
+
+impl ParseColorError {
+    /// Return the string that could not be parsed.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+}
+
+impl Display for ParseColorError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "invalid color `{}`, expected `#rrggbb`", self.input)
+    }
+}
+
+impl Error for ParseColorError {}
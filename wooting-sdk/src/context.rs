@@ -0,0 +1,168 @@
+//! Contains the context that owns the connection to Wooting's SDKs.
+
+use super::backend::{ContextBackend, FfiBackend};
+use super::{KeyboardModel, PhysicalLayout};
+
+#[cfg(feature = "analog")]
+use super::{analog::AnalogReader, backend::AnalogBackend, ConnectionWatcher};
+#[cfg(feature = "rgb")]
+use super::{backend::RgbBackend, rgb::RgbKeyboard, WootingError};
+
+/// Owns the connection to Wooting's SDKs and hands out the readers and writers that use it.
+/// Operations are performed through a backend, which is the [`FfiBackend`][ffi] by default.
+///
+/// Any number of [`AnalogReader`][reader]s can be handed out, but only one
+/// [`RgbKeyboard`][keyboard] can control the keyboard's LEDs at a time, so that two parts of a
+/// program cannot overwrite each other's colors. The keyboard restores the original colors and
+/// gives up control when it is dropped, after which another can be handed out. When the context
+/// is dropped, the keyboard's original colors are restored too, unless a keyboard that it
+/// handed out still has control of the LEDs.
+///
+/// The free functions in the `analog` and `rgb` modules remain available and use the
+/// `FfiBackend` directly.
+///
+/// ```rust
+/// # #[cfg(all(feature = "analog", feature = "rgb"))] {
+/// # fn test() -> Result<(), wooting_sdk::WootingError> {
+/// use wooting_sdk::{backend::SimulatedKeyboard, Key, Wooting, WootingError};
+///
+/// let simulated = SimulatedKeyboard::new();
+/// let wooting = Wooting::with_backend(simulated.clone());
+///
+/// // Read the analog value of W..
+/// simulated.set_analog_value(Key::W, 255);
+/// assert_eq!(wooting.analog().read_analog_key(Key::W)?, 255);
+///
+/// // ..and light it up, which stops anything else from changing the colors..
+/// let mut keyboard = wooting.rgb()?;
+/// keyboard.direct_set_key(Key::W, (255, 0, 0))?;
+/// assert_eq!(wooting.rgb().unwrap_err(), WootingError::InUse);
+///
+/// // ..until the keyboard is dropped, which resets the colors.
+/// drop(keyboard);
+/// assert_eq!(simulated.displayed().color(Key::W), None);
+/// assert!(wooting.rgb().is_ok());
+///
+/// // Dropping the context resets the colors too.
+/// let frames = simulated.frames().len();
+/// drop(wooting);
+/// assert_eq!(simulated.frames().len(), frames + 1);
+/// # Ok(())
+/// # }
+/// # test().unwrap();
+/// # }
+/// ```
+///
+/// [ffi]: backend/struct.FfiBackend.html
+/// [reader]: analog/struct.AnalogReader.html
+/// [keyboard]: rgb/struct.RgbKeyboard.html
+#[derive(Debug)]
+pub struct Wooting<B: ContextBackend = FfiBackend> {
+    backend: B,
+    model: KeyboardModel,
+    layout: PhysicalLayout,
+}
+
+impl Wooting {
+    /// Create a context that uses Wooting's SDKs, opening the connection to the keyboard if one
+    /// is connected.
+    ///
+    /// ```rust,no_run
+    /// # #[cfg(feature = "rgb")]
+    /// # fn test() -> Result<(), wooting_sdk::WootingError> {
+    /// use wooting_sdk::{Key, Wooting};
+    ///
+    /// let wooting = Wooting::new();
+    /// let mut keyboard = wooting.rgb()?;
+    /// keyboard.direct_set_key(Key::A, (255, 255, 255))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn new() -> Self {
+        // The SDKs open the keyboard the first time that they check whether it is connected.
+        #[cfg(feature = "analog")]
+        let _ = AnalogBackend::is_connected(&FfiBackend);
+        #[cfg(feature = "rgb")]
+        let _ = RgbBackend::is_connected(&FfiBackend);
+
+        Wooting::with_backend(FfiBackend)
+    }
+}
+
+impl<B: ContextBackend> Wooting<B> {
+    /// Create a context for an ANSI Wooting Two that performs operations through the given
+    /// backend.
+    pub fn with_backend(backend: B) -> Self {
+        Wooting {
+            backend,
+            model: KeyboardModel::default(),
+            layout: PhysicalLayout::default(),
+        }
+    }
+
+    /// Return the model of keyboard that readers and writers are handed out for.
+    pub fn model(&self) -> KeyboardModel {
+        self.model
+    }
+
+    /// Set the model of keyboard that readers and writers are handed out for.
+    pub fn set_model(&mut self, model: KeyboardModel) {
+        self.model = model;
+    }
+
+    /// Return the physical layout of the keyboard that readers are handed out for.
+    pub fn layout(&self) -> PhysicalLayout {
+        self.layout
+    }
+
+    /// Set the physical layout of the keyboard that readers are handed out for.
+    pub fn set_layout(&mut self, layout: PhysicalLayout) {
+        self.layout = layout;
+    }
+
+    /// Return the backend that operations are performed through.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+}
+
+#[cfg(feature = "analog")]
+impl<B: AnalogBackend + ContextBackend + Clone> Wooting<B> {
+    /// Return a reader for the analog values of the keyboard.
+    pub fn analog(&self) -> AnalogReader<B> {
+        let mut reader = AnalogReader::with_model(self.backend.clone(), self.model);
+        reader.set_layout(self.layout);
+        reader
+    }
+}
+
+#[cfg(feature = "analog")]
+impl<B: AnalogBackend + ContextBackend + Clone + Send + 'static> Wooting<B> {
+    /// Return a watcher that notices when the keyboard is connected or disconnected.
+    pub fn watch(&self) -> ConnectionWatcher {
+        ConnectionWatcher::with_backend(self.backend.clone())
+    }
+}
+
+#[cfg(feature = "rgb")]
+impl<B: RgbBackend + ContextBackend + Clone> Wooting<B> {
+    /// Return a writer for the colors of the keyboard, which has control of the keyboard's LEDs
+    /// until it is dropped.
+    ///
+    /// Returns `Err(WootingError::InUse)` if another `RgbKeyboard` has control of the LEDs.
+    pub fn rgb(&self) -> Result<RgbKeyboard<B>, WootingError> {
+        RgbKeyboard::with_model(self.backend.clone(), self.model)
+    }
+}
+
+impl Default for Wooting {
+    fn default() -> Self {
+        Wooting::new()
+    }
+}
+
+impl<B: ContextBackend> Drop for Wooting<B> {
+    fn drop(&mut self) {
+        self.backend.close();
+    }
+}
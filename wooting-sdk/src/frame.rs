@@ -0,0 +1,217 @@
+//! Contains a frame with a color for every key, stored in the layout that the RGB SDK takes.
+
+use super::rgb::Color;
+use super::{FromMatrixRowColumn, IntoMatrixRowColumn, Key, KeySet, COLUMNS, COMPONENTS, ROWS};
+
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Represents the color of every key on the keyboard, stored row by row in the matrix layout that
+/// the RGB SDK takes, so that a frame can be sent to the keyboard as-is (see
+/// `RgbKeyboard::commit`). Positions in the matrix without a key are kept black.
+///
+/// ```rust
+/// use wooting_sdk::{
+///     rgb::{Color, RgbFrame},
+///     Key, KeySet,
+/// };
+///
+/// // Start with every key blue..
+/// let mut frame = RgbFrame::new(Color::BLUE);
+/// // ..make the letters white..
+/// frame.fill_keys(KeySet::LETTERS, Color::WHITE);
+/// // ..and escape red.
+/// frame[Key::Escape] = Color::RED;
+///
+/// assert_eq!(frame[Key::Q], Color::WHITE);
+/// assert_eq!(frame[Key::Escape], Color::RED);
+/// let blue = frame.iter().filter(|&(_, color)| color == Color::BLUE).count();
+/// assert_eq!(blue, Key::COUNT - KeySet::LETTERS.len() - 1);
+///
+/// // Escape is the first key of the buffer that is sent to the keyboard.
+/// assert_eq!(&frame.as_buffer()[..3], &[255, 0, 0]);
+/// ```
+#[derive(Clone, Copy, Default, Hash, Eq, PartialEq)]
+pub struct RgbFrame {
+    colors: [[Color; COLUMNS]; ROWS],
+}
+
+impl RgbFrame {
+    /// Create a frame with every key set to the same color.
+    pub fn new<C: Into<Color>>(color: C) -> Self {
+        let mut frame = RgbFrame::default();
+        frame.fill(color);
+        frame
+    }
+
+    /// Create a frame from the flattened buffer that the RGB SDK's `array_set_full` takes. Colors
+    /// at positions without a key are ignored.
+    pub fn from_buffer(buffer: &[u8; COMPONENTS * COLUMNS * ROWS]) -> Self {
+        let mut frame = RgbFrame::default();
+        for key in Key::iter() {
+            let (row, column) = key.into_matrix_row_and_column();
+            let index = (row as usize * COLUMNS + column as usize) * COMPONENTS;
+            frame[key] = Color::new(buffer[index], buffer[index + 1], buffer[index + 2]);
+        }
+        frame
+    }
+
+    /// Return the frame as the flattened buffer that the RGB SDK's `array_set_full` takes,
+    /// without copying it.
+    pub fn as_buffer(&self) -> &[u8; COMPONENTS * COLUMNS * ROWS] {
+        // `Color` is `repr(C)` with three `u8` components, so it has the same size and alignment
+        // as `[u8; COMPONENTS]`, and the rows of colors are laid out exactly like the buffer.
+        let colors: *const [[Color; COLUMNS]; ROWS] = &self.colors;
+        unsafe { &*(colors as *const [u8; COMPONENTS * COLUMNS * ROWS]) }
+    }
+
+    /// Set every key to the same color.
+    pub fn fill<C: Into<Color>>(&mut self, color: C) {
+        let color = color.into();
+        for key in Key::iter() {
+            self[key] = color;
+        }
+    }
+
+    /// Set every key in the set to the same color.
+    pub fn fill_keys<C: Into<Color>>(&mut self, keys: KeySet, color: C) {
+        let color = color.into();
+        for key in keys {
+            self[key] = color;
+        }
+    }
+
+    /// Return an iterator over every key and its color, in matrix order.
+    pub fn iter(&self) -> impl Iterator<Item = (Key, Color)> + '_ {
+        Key::iter().map(move |key| (key, self[key]))
+    }
+
+    /// Return an iterator over every key and a mutable reference to its color, in matrix order.
+    ///
+    /// ```rust
+    /// use wooting_sdk::rgb::{Color, RgbFrame};
+    ///
+    /// // Dim every key by half.
+    /// let mut frame = RgbFrame::new(Color::WHITE);
+    /// for (_, color) in frame.iter_mut() {
+    ///     *color = Color::BLACK.lerp(*color, 0.5);
+    /// }
+    /// assert!(frame.iter().all(|(_, color)| color == Color::new(128, 128, 128)));
+    /// ```
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Key, &mut Color)> {
+        self.colors
+            .iter_mut()
+            .enumerate()
+            .flat_map(|(row, colors)| {
+                colors
+                    .iter_mut()
+                    .enumerate()
+                    .map(move |(column, color)| (row, column, color))
+            })
+            .filter_map(|(row, column, color)| {
+                Key::from_matrix_row_and_column(row as u8, column as u8).map(|key| (key, color))
+            })
+    }
+}
+
+/// Represents how `RgbKeyboard::commit` sent a frame to the keyboard.
+///
+/// A frame that changes a key that cannot be written directly (see
+/// `RgbKeyboard::direct_set_key`) is sent as the color array, however few keys changed.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CommitStrategy {
+    /// Indicates that the keyboard was already showing the frame, so nothing was sent.
+    Unchanged,
+    /// Indicates that each key that changed was written directly.
+    PerKey,
+    /// Indicates that the whole frame was sent as the color array.
+    Full,
+}
+
+/// Represents how much was sent to the keyboard to commit a frame, as returned by
+/// `RgbKeyboard::commit`. Argument bytes count the positions and colors that were passed as
+/// arguments to the RGB SDK: five (the row, column and three components) for each key written
+/// directly, or the whole buffer for the color array. They are not the number of bytes that the
+/// RGB SDK sends to the keyboard, which depends on its USB protocol.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CommitStats {
+    /// How the frame was sent.
+    pub strategy: CommitStrategy,
+    /// How many keys changed color since the last commit.
+    pub changed: usize,
+    /// How many calls were made to the RGB SDK.
+    pub calls: usize,
+    /// How many bytes were passed as arguments to the RGB SDK.
+    pub argument_bytes: usize,
+}
+
+impl CommitStats {
+    /// How many bytes are passed as arguments to the RGB SDK to write a single key directly.
+    pub const PER_KEY_ARGUMENT_BYTES: usize = 2 + COMPONENTS;
+    /// How many bytes are passed as arguments to the RGB SDK to set the color array.
+    pub const FULL_ARGUMENT_BYTES: usize = COMPONENTS * COLUMNS * ROWS;
+}
+
+impl Index<Key> for RgbFrame {
+    type Output = Color;
+
+    fn index(&self, key: Key) -> &Color {
+        let (row, column) = key.into_matrix_row_and_column();
+        &self.colors[row as usize][column as usize]
+    }
+}
+
+impl IndexMut<Key> for RgbFrame {
+    fn index_mut(&mut self, key: Key) -> &mut Color {
+        let (row, column) = key.into_matrix_row_and_column();
+        &mut self.colors[row as usize][column as usize]
+    }
+}
+
+impl fmt::Debug for RgbFrame {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_map().entries(self.iter()).finish()
+    }
+}
+
+/// Serializes a frame as a map from key names to colors.
+///
+/// ```rust
+/// use wooting_sdk::{
+///     rgb::{Color, RgbFrame},
+///     Key,
+/// };
+///
+/// let mut frame = RgbFrame::default();
+/// frame[Key::W] = Color::RED;
+/// let json = serde_json::to_string(&frame).unwrap();
+/// assert_eq!(serde_json::from_str::<RgbFrame>(&json).unwrap(), frame);
+///
+/// // Keys that are missing are deserialized as black.
+/// let frame: RgbFrame =
+///     serde_json::from_str(r#"{"W":{"red":255,"green":0,"blue":0}}"#).unwrap();
+/// assert_eq!(frame[Key::W], Color::RED);
+/// assert_eq!(frame[Key::A], Color::BLACK);
+/// ```
+#[cfg(feature = "serde")]
+impl serde::Serialize for RgbFrame {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+/// Deserializes a frame from a map from keys to colors. Keys that are missing are black.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for RgbFrame {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let colors: std::collections::HashMap<Key, Color> =
+            serde::Deserialize::deserialize(deserializer)?;
+        let mut frame = RgbFrame::default();
+        for (key, color) in colors {
+            frame[key] = color;
+        }
+        Ok(frame)
+    }
+}
@@ -0,0 +1,178 @@
+//! Contains the physical geometry of keys, for effects that follow the shape of the keyboard
+//! rather than the LED matrix.
+
+use super::{Key, KeyboardModel, PhysicalLayout};
+
+/// How many millimetres are there in a key unit? A key unit is the width of a letter key,
+/// including the gap to its neighbours.
+pub const KEY_UNIT_MM: f32 = 19.05;
+
+/// Represents the physical position and size of a key, in key units. The origin is the top left
+/// corner of the `Escape` key, with `x` increasing to the right and `y` increasing downwards.
+///
+/// Keys that are not rectangular (such as the ISO return key) are described by their bounding
+/// box and the part of it that they do not cover.
+///
+/// ```rust
+/// use wooting_sdk::{Key, KeyboardModel, PhysicalLayout};
+///
+/// // Space is not in the middle of the LED matrix, but it is in the middle of the keyboard..
+/// let space = Key::Space
+///     .geometry(KeyboardModel::WootingOne, PhysicalLayout::Ansi)
+///     .unwrap();
+/// assert_eq!(space.center(), (6.875, 6.0));
+/// assert_eq!(space.width, 6.25);
+///
+/// // ..the Wooting One has no numpad..
+/// assert_eq!(
+///     Key::NumFive.geometry(KeyboardModel::WootingOne, PhysicalLayout::Ansi),
+///     None
+/// );
+///
+/// // ..and the return key is taller on ISO keyboards.
+/// let ansi = Key::Return.geometry(KeyboardModel::WootingTwo, PhysicalLayout::Ansi);
+/// let iso = Key::Return.geometry(KeyboardModel::WootingTwo, PhysicalLayout::Iso);
+/// assert_eq!(ansi.unwrap().height, 1.0);
+/// assert_eq!(iso.unwrap().height, 2.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct KeyGeometry {
+    /// Distance from the left edge of the keyboard to the left edge of the key.
+    pub x: f32,
+    /// Distance from the top edge of the keyboard to the top edge of the key.
+    pub y: f32,
+    /// Width of the key.
+    pub width: f32,
+    /// Height of the key.
+    pub height: f32,
+    /// Part of the bounding box that the key does not cover, as `(x, y, width, height)`, if the
+    /// key is not rectangular. The ISO return key does not cover the bottom left corner of its
+    /// bounding box, which is where `ISO1` is.
+    pub cutout: Option<(f32, f32, f32, f32)>,
+}
+
+impl KeyGeometry {
+    /// Return the point `(x, y)` at the center of the key.
+    pub fn center(&self) -> (f32, f32) {
+        (self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+
+    /// Does the key cover the point `(x, y)`?
+    ///
+    /// ```rust
+    /// use wooting_sdk::{Key, KeyboardModel, PhysicalLayout};
+    ///
+    /// let escape = Key::Escape
+    ///     .geometry(KeyboardModel::WootingTwo, PhysicalLayout::Ansi)
+    ///     .unwrap();
+    /// assert!(escape.contains(0.5, 0.5));
+    /// assert!(!escape.contains(1.5, 0.5));
+    ///
+    /// // The ISO return key does not cover `ISO1`, which is in the corner of its bounding box.
+    /// let (model, layout) = (KeyboardModel::WootingTwo, PhysicalLayout::Iso);
+    /// let iso_return = Key::Return.geometry(model, layout).unwrap();
+    /// assert!(iso_return.contains(13.6, 3.0));
+    /// assert!(!iso_return.contains(13.6, 4.0));
+    /// assert!(Key::ISO1.geometry(model, layout).unwrap().contains(13.6, 4.0));
+    /// ```
+    pub fn contains(&self, x: f32, y: f32) -> bool {
+        let within = |(left, top, width, height): (f32, f32, f32, f32)| {
+            x >= left && x < left + width && y >= top && y < top + height
+        };
+        within((self.x, self.y, self.width, self.height)) && !self.cutout.map_or(false, within)
+    }
+
+    /// Return the distance from the center of the key to the point `(x, y)`. Useful for radial
+    /// effects, such as gradients and ripples.
+    ///
+    /// ```rust
+    /// use wooting_sdk::{Key, KeyboardModel, PhysicalLayout};
+    ///
+    /// let (model, layout) = (KeyboardModel::WootingTwo, PhysicalLayout::Ansi);
+    /// let (x, y) = Key::G.geometry(model, layout).unwrap().center();
+    /// // H is closer to G than J is.
+    /// let h = Key::H.geometry(model, layout).unwrap().distance_to(x, y);
+    /// let j = Key::J.geometry(model, layout).unwrap().distance_to(x, y);
+    /// assert!(h < j);
+    /// ```
+    pub fn distance_to(&self, x: f32, y: f32) -> f32 {
+        let (center_x, center_y) = self.center();
+        ((center_x - x).powi(2) + (center_y - y).powi(2)).sqrt()
+    }
+
+    /// Return the same geometry, in millimetres rather than key units.
+    pub fn to_millimetres(&self) -> KeyGeometry {
+        KeyGeometry {
+            x: self.x * KEY_UNIT_MM,
+            y: self.y * KEY_UNIT_MM,
+            width: self.width * KEY_UNIT_MM,
+            height: self.height * KEY_UNIT_MM,
+            cutout: self.cutout.map(|(x, y, width, height)| {
+                (
+                    x * KEY_UNIT_MM,
+                    y * KEY_UNIT_MM,
+                    width * KEY_UNIT_MM,
+                    height * KEY_UNIT_MM,
+                )
+            }),
+        }
+    }
+}
+
+impl Key {
+    /// Return the physical position and size of the key on the given model with the given
+    /// layout, or `None` if a keyboard with that model and layout does not have the key.
+    ///
+    /// ```rust
+    /// use wooting_sdk::{Key, KeyboardModel, PhysicalLayout};
+    ///
+    /// let model = KeyboardModel::WootingTwo;
+    /// let (width, height) = model.size();
+    /// for &layout in &[PhysicalLayout::Ansi, PhysicalLayout::Iso] {
+    ///     // No point is covered by two keys on either layout. Keys are positioned in quarters
+    ///     // of a key unit, so the middle of every quarter by quarter square is checked.
+    ///     let keys: Vec<_> = model
+    ///         .layout_keys(layout)
+    ///         .map(|key| key.geometry(model, layout).unwrap())
+    ///         .collect();
+    ///     for row in 0..(height * 4.0) as usize {
+    ///         for column in 0..(width * 4.0) as usize {
+    ///             let (x, y) = ((column as f32 + 0.5) / 4.0, (row as f32 + 0.5) / 4.0);
+    ///             assert!(keys.iter().filter(|key| key.contains(x, y)).count() <= 1);
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(Key::ISO1.geometry(model, PhysicalLayout::Ansi), None);
+    /// ```
+    pub fn geometry(self, model: KeyboardModel, layout: PhysicalLayout) -> Option<KeyGeometry> {
+        if self.is_available_on(model) && self.is_available_on_layout(layout) {
+            Some(self.info().geometry(layout))
+        } else {
+            None
+        }
+    }
+}
+
+impl KeyboardModel {
+    /// Return the size `(width, height)` of the area covered by the keys of this model, in key
+    /// units. This is the same for both layouts.
+    ///
+    /// ```rust
+    /// use wooting_sdk::KeyboardModel;
+    ///
+    /// assert_eq!(KeyboardModel::WootingOne.size(), (18.25, 6.5));
+    /// assert_eq!(KeyboardModel::WootingTwo.size(), (22.5, 6.5));
+    /// ```
+    pub fn size(self) -> (f32, f32) {
+        self.keys()
+            .filter_map(|key| key.geometry(self, PhysicalLayout::Ansi))
+            .fold((0.0, 0.0), |(width, height), geometry| {
+                (
+                    width.max(geometry.x + geometry.width),
+                    height.max(geometry.y + geometry.height),
+                )
+            })
+    }
+}
@@ -0,0 +1,1538 @@
+//! Contains the table of metadata about every key, from which all of the conversions for `Key`
+//! are generated.
+
+use super::{
+    FromMatrixRowColumn, FromScanIndex, IntoMatrixRowColumn, IntoScanIndex, KeyGeometry,
+    PhysicalLayout,
+};
+
+use std::fmt::{self, Display};
+
+use lazy_static::lazy_static;
+
+/// Metadata about a key on the keyboard.
+///
+/// ```rust
+/// use wooting_sdk::Key;
+///
+/// let info = Key::Escape.info();
+/// assert_eq!(info.name(), "Escape");
+/// assert_eq!(info.label(), "Esc");
+/// assert_eq!(info.scan_index(), 0);
+/// assert_eq!(info.matrix_row_and_column(), (0, 0));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct KeyInfo {
+    key: Key,
+    name: &'static str,
+    label: &'static str,
+    scan_index: u8,
+    row: u8,
+    column: u8,
+    geometry: KeyGeometry,
+    iso_geometry: KeyGeometry,
+    layout: Option<PhysicalLayout>,
+    aliases: &'static [&'static str],
+    hid_usage: Option<u16>,
+    evdev_code: Option<u16>,
+}
+
+impl KeyInfo {
+    /// Return the metadata of every key, in the order that the keys are declared in (matrix
+    /// order).
+    ///
+    /// Every key converts to and from its scan index and matrix position:
+    ///
+    /// ```rust
+    /// use wooting_sdk::{
+    ///     FromMatrixRowColumn, FromScanIndex, IntoMatrixRowColumn, IntoScanIndex, Key, KeyInfo,
+    /// };
+    ///
+    /// assert_eq!(KeyInfo::all().len(), Key::COUNT);
+    /// for (info, key) in KeyInfo::all().iter().zip(Key::iter()) {
+    ///     assert_eq!(info.key(), key);
+    ///     assert_eq!(key.info(), info);
+    ///     assert_eq!(key.into_scan_index(), info.scan_index());
+    ///     assert_eq!(key.into_matrix_row_and_column(), info.matrix_row_and_column());
+    ///     let (row, column) = info.matrix_row_and_column();
+    ///     assert_eq!(Key::from_matrix_row_and_column(row, column), Some(key));
+    ///     assert_eq!(key.to_string(), info.label());
+    ///     assert_eq!(format!("{:?}", key), info.name());
+    /// }
+    ///
+    /// // ..and every scan index that is associated with a key converts back to that scan index
+    /// // (which for the scan index that `Backslash` and `ISO1` share is `Backslash`).
+    /// for index in u8::min_value()..=u8::max_value() {
+    ///     if let Some(key) = Key::from_scan_index(index) {
+    ///         assert_eq!(key.into_scan_index(), index);
+    ///     }
+    /// }
+    /// ```
+    pub fn all() -> &'static [KeyInfo] {
+        KEY_INFO
+    }
+
+    /// Return the key that this metadata describes.
+    pub fn key(&self) -> Key {
+        self.key
+    }
+
+    /// Return the name of the key, which is the same as the name of its `Key` variant.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Return the label of the key, as printed on a US keyboard. This is used by the `Display`
+    /// implementation of `Key`, see `Key::label` for the labels printed on other keyboards.
+    pub fn label(&self) -> &'static str {
+        self.label
+    }
+
+    /// Return the scan index of the key, as reported by the Analog SDK.
+    pub fn scan_index(&self) -> u8 {
+        self.scan_index
+    }
+
+    /// Return a tuple `(row, column)` that represents the matrix row and column of the key.
+    pub fn matrix_row_and_column(&self) -> (u8, u8) {
+        (self.row, self.column)
+    }
+
+    /// Return the physical position and size of the key, in key units, on every model that has
+    /// the key with the given layout. Keys that do not exist on the layout are positioned as on
+    /// the layout that has them.
+    pub fn geometry(&self, layout: PhysicalLayout) -> KeyGeometry {
+        match layout {
+            PhysicalLayout::Ansi => self.geometry,
+            PhysicalLayout::Iso => self.iso_geometry,
+        }
+    }
+
+    /// Return the only layout that has the key, or `None` if every layout has the key.
+    pub fn layout(&self) -> Option<PhysicalLayout> {
+        self.layout
+    }
+
+    /// Return the other names that the key is parsed from, in addition to its name and label.
+    /// Aliases are lowercase and contain no spaces or underscores.
+    ///
+    /// ```rust
+    /// use wooting_sdk::Key;
+    ///
+    /// assert!(Key::LeftControl.info().aliases().contains(&"ctrll"));
+    /// ```
+    pub fn aliases(&self) -> &'static [&'static str] {
+        self.aliases
+    }
+
+    /// Return the USB HID usage ID of the key on the keyboard/keypad usage page (`0x07`), or
+    /// `None` if the key has no HID equivalent.
+    pub fn hid_usage(&self) -> Option<u16> {
+        self.hid_usage
+    }
+
+    /// Return the Linux evdev keycode (`KEY_*` in `input-event-codes.h`) of the key, or `None` if
+    /// the key has no evdev equivalent.
+    pub fn evdev_code(&self) -> Option<u16> {
+        self.evdev_code
+    }
+}
+
+/// Declares `Key` and every conversion for it from a single table of metadata, so that the
+/// conversions cannot disagree with each other. Each scan index, matrix position and HID usage
+/// must be a literal so that a duplicate is caught by the unreachable pattern lint. A key whose
+/// scan index is deliberately shared names the key that it shares it with, and the scan index
+/// converts to the key that names the other unless the layout only has the other key.
+macro_rules! keys {
+    (@geometry ($x:expr, $y:expr, $width:expr, $height:expr)) => {
+        KeyGeometry {
+            x: $x,
+            y: $y,
+            width: $width,
+            height: $height,
+            cutout: None,
+        }
+    };
+    (@geometry ($x:expr, $y:expr, $width:expr, $height:expr, cutout: $cutout:expr)) => {
+        KeyGeometry {
+            x: $x,
+            y: $y,
+            width: $width,
+            height: $height,
+            cutout: Some($cutout),
+        }
+    };
+    (@iso_geometry $geometry:tt) => { keys!(@geometry $geometry) };
+    (@iso_geometry $geometry:tt $iso_geometry:tt) => { keys!(@geometry $iso_geometry) };
+    (@layout) => { None };
+    (@layout $layout:ident) => { Some(PhysicalLayout::$layout) };
+    (@aliases) => { &[] };
+    (@aliases $($alias:literal),*) => { &[$($alias),*] };
+    (@option) => { None };
+    (@option $value:literal) => { Some($value) };
+    (@shared $shared:ident) => { true };
+    ($(
+        $(#[$attr:meta])*
+        $key:ident {
+            label: $label:expr,
+            scan_index: $scan_index:literal,
+            $(scan_index_shared_with: $shared:ident,)?
+            matrix: ($row:literal, $column:literal),
+            geometry: $geometry:tt,
+            $(iso_geometry: $iso_geometry:tt,)?
+            $(layout: $layout:ident,)?
+            $(aliases: [$($alias:literal),* $(,)?],)?
+            $(hid: $hid:literal,)?
+            $(evdev: $evdev:literal,)?
+        }
+    )*) => {
+        /// Represents a key on the keyboard.
+        #[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+        pub enum Key {
+            $(
+                $(#[$attr])*
+                $key,
+            )*
+        }
+
+        impl Key {
+            /// Every key, in matrix order.
+            pub const ALL: &'static [Key] = &[$(Key::$key,)*];
+        }
+
+        /// Metadata of every key, indexed by the discriminant of its `Key` variant.
+        const KEY_INFO: &[KeyInfo] = &[
+            $(
+                KeyInfo {
+                    key: Key::$key,
+                    name: stringify!($key),
+                    label: $label,
+                    scan_index: $scan_index,
+                    row: $row,
+                    column: $column,
+                    geometry: keys!(@geometry $geometry),
+                    iso_geometry: keys!(@iso_geometry $geometry $($iso_geometry)?),
+                    layout: keys!(@layout $($layout)?),
+                    aliases: keys!(@aliases $($($alias),*)?),
+                    hid_usage: keys!(@option $($hid)?),
+                    evdev_code: keys!(@option $($evdev)?),
+                },
+            )*
+        ];
+
+        impl FromScanIndex for Key {
+            /// Return the key that corresponds to the provided scan index, if any.
+            fn from_scan_index(index: u8) -> Option<Self> {
+                match index {
+                    $($scan_index $(if keys!(@shared $shared))? => Some(Key::$key),)*
+                    _ => None,
+                }
+            }
+
+            /// Return the key that corresponds to the provided scan index on a keyboard with the
+            /// given layout, if any. Unlike `from_scan_index`, this resolves the scan index that
+            /// `Backslash` and `ISO1` share to the key that the layout has.
+            ///
+            /// ```rust
+            /// use wooting_sdk::{FromScanIndex, Key, PhysicalLayout};
+            ///
+            /// assert_eq!(Key::from_scan_index(45), Some(Key::Backslash));
+            /// assert_eq!(Key::from_scan_index_for(45, PhysicalLayout::Iso), Some(Key::ISO1));
+            /// let ansi = PhysicalLayout::Ansi;
+            /// assert_eq!(Key::from_scan_index_for(45, ansi), Some(Key::Backslash));
+            /// assert_eq!(Key::from_scan_index_for(0, PhysicalLayout::Ansi), Some(Key::Escape));
+            /// // Scan indices 88 and 89 are not associated with a key.
+            /// assert_eq!(Key::from_scan_index_for(88, PhysicalLayout::Ansi), None);
+            /// ```
+            fn from_scan_index_for(index: u8, layout: PhysicalLayout) -> Option<Self> {
+                match Key::from_scan_index(index) {
+                    $($(
+                        Some(Key::$key) if !Key::$key.is_available_on_layout(layout) => {
+                            Some(Key::$shared)
+                        }
+                    )?)*
+                    key => key,
+                }
+            }
+        }
+
+        impl Key {
+            /// Return the key that corresponds to the provided USB HID usage ID on the
+            /// keyboard/keypad usage page (`0x07`), if any.
+            ///
+            /// ```rust
+            /// use wooting_sdk::Key;
+            ///
+            /// assert_eq!(Key::from_hid_usage(0x04), Some(Key::A));
+            /// assert_eq!(Key::from_hid_usage(0x64), Some(Key::ISO2));
+            /// // Volume Up has no key on a Wooting keyboard.
+            /// assert_eq!(Key::from_hid_usage(0x80), None);
+            /// ```
+            pub fn from_hid_usage(usage: u16) -> Option<Self> {
+                match usage {
+                    $($($hid => Some(Key::$key),)?)*
+                    _ => None,
+                }
+            }
+        }
+
+        impl FromMatrixRowColumn for Key {
+            /// Return the key at the provided matrix row and column, if any.
+            fn from_matrix_row_and_column(row: u8, column: u8) -> Option<Self> {
+                match (row, column) {
+                    $(($row, $column) => Some(Key::$key),)*
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+keys! {
+    /// Escape key (`Esc`). Generates the escape character (ASCII 27).
+    Escape {
+        label: "Esc",
+        scan_index: 0,
+        matrix: (0, 0),
+        geometry: (0.0, 0.0, 1.0, 1.0),
+        aliases: ["esc"],
+        hid: 0x29,
+        evdev: 1,
+    }
+    /// Function key (`F1`). Normally programmed to cause an operating system or application to
+    /// perform certain actions.
+    F1 {
+        label: "F1",
+        scan_index: 1,
+        matrix: (0, 2),
+        geometry: (2.0, 0.0, 1.0, 1.0),
+        hid: 0x3A,
+        evdev: 59,
+    }
+    /// Function key (`F2`). Normally programmed to cause an operating system or application to
+    /// perform certain actions.
+    F2 {
+        label: "F2",
+        scan_index: 2,
+        matrix: (0, 3),
+        geometry: (3.0, 0.0, 1.0, 1.0),
+        hid: 0x3B,
+        evdev: 60,
+    }
+    /// Function key (`F3`). Normally programmed to cause an operating system or application to
+    /// perform certain actions.
+    F3 {
+        label: "F3",
+        scan_index: 3,
+        matrix: (0, 4),
+        geometry: (4.0, 0.0, 1.0, 1.0),
+        hid: 0x3C,
+        evdev: 61,
+    }
+    /// Function key (`F4`). Normally programmed to cause an operating system or application to
+    /// perform certain actions.
+    F4 {
+        label: "F4",
+        scan_index: 4,
+        matrix: (0, 5),
+        geometry: (5.0, 0.0, 1.0, 1.0),
+        hid: 0x3D,
+        evdev: 62,
+    }
+    /// Function key (`F5`). Normally programmed to cause an operating system or application to
+    /// perform certain actions.
+    F5 {
+        label: "F5",
+        scan_index: 5,
+        matrix: (0, 6),
+        geometry: (6.5, 0.0, 1.0, 1.0),
+        hid: 0x3E,
+        evdev: 63,
+    }
+    /// Function key (`F6`). Normally programmed to cause an operating system or application to
+    /// perform certain actions.
+    F6 {
+        label: "F6",
+        scan_index: 6,
+        matrix: (0, 7),
+        geometry: (7.5, 0.0, 1.0, 1.0),
+        hid: 0x3F,
+        evdev: 64,
+    }
+    /// Function key (`F7`). Normally programmed to cause an operating system or application to
+    /// perform certain actions.
+    F7 {
+        label: "F7",
+        scan_index: 7,
+        matrix: (0, 8),
+        geometry: (8.5, 0.0, 1.0, 1.0),
+        hid: 0x40,
+        evdev: 65,
+    }
+    /// Function key (`F8`). Normally programmed to cause an operating system or application to
+    /// perform certain actions.
+    F8 {
+        label: "F8",
+        scan_index: 8,
+        matrix: (0, 9),
+        geometry: (9.5, 0.0, 1.0, 1.0),
+        hid: 0x41,
+        evdev: 66,
+    }
+    /// Function key (`F9`). Normally programmed to cause an operating system or application to
+    /// perform certain actions.
+    F9 {
+        label: "F9",
+        scan_index: 9,
+        matrix: (0, 10),
+        geometry: (11.0, 0.0, 1.0, 1.0),
+        hid: 0x42,
+        evdev: 67,
+    }
+    /// Function key (`F10`). Normally programmed to cause an operating system or application to
+    /// perform certain actions.
+    F10 {
+        label: "F10",
+        scan_index: 10,
+        matrix: (0, 11),
+        geometry: (12.0, 0.0, 1.0, 1.0),
+        hid: 0x43,
+        evdev: 68,
+    }
+    /// Function key (`F11`). Normally programmed to cause an operating system or application to
+    /// perform certain actions.
+    F11 {
+        label: "F11",
+        scan_index: 11,
+        matrix: (0, 12),
+        geometry: (13.0, 0.0, 1.0, 1.0),
+        hid: 0x44,
+        evdev: 87,
+    }
+    /// Function key (`F12`). Normally programmed to cause an operating system or application to
+    /// perform certain actions.
+    F12 {
+        label: "F12",
+        scan_index: 12,
+        matrix: (0, 13),
+        geometry: (14.0, 0.0, 1.0, 1.0),
+        hid: 0x45,
+        evdev: 88,
+    }
+    /// Print screen key (`Prt Sc`). May share the same key as system request. Normally takes a
+    /// screenshot.
+    PrintScreen {
+        label: "Print Screen",
+        scan_index: 13,
+        matrix: (0, 14),
+        geometry: (15.25, 0.0, 1.0, 1.0),
+        aliases: ["prtsc", "prtscn", "print", "sysrq"],
+        hid: 0x46,
+        evdev: 99,
+    }
+    /// Pause (or break) key. Has no well defined purpose.
+    Pause {
+        label: "Pause",
+        scan_index: 14,
+        matrix: (0, 15),
+        geometry: (16.25, 0.0, 1.0, 1.0),
+        aliases: ["break"],
+        hid: 0x48,
+        evdev: 119,
+    }
+    /// Scroll lock key (`Scr Lk`). Has different functions or purposes depending on the
+    /// application or operating system. Originally intended to make arrow keys scroll the current
+    /// window contents instead of moving the cursor.
+    ScrollLock {
+        label: "Scroll Lock",
+        scan_index: 15,
+        matrix: (0, 16),
+        geometry: (17.25, 0.0, 1.0, 1.0),
+        aliases: ["scrlk"],
+        hid: 0x47,
+        evdev: 70,
+    }
+    /// Analog profile key (`A1`). Switches to analog profile one.
+    A1 {
+        label: "A1",
+        scan_index: 107,
+        matrix: (0, 17),
+        geometry: (18.5, 0.0, 1.0, 1.0),
+    }
+    /// Analog profile key (`A2`). Switches to analog profile two.
+    A2 {
+        label: "A2",
+        scan_index: 108,
+        matrix: (0, 18),
+        geometry: (19.5, 0.0, 1.0, 1.0),
+    }
+    /// Analog profile key (`A3`). Switches to analog profile three.
+    A3 {
+        label: "A3",
+        scan_index: 109,
+        matrix: (0, 19),
+        geometry: (20.5, 0.0, 1.0, 1.0),
+    }
+    /// Mode key. Toggles between digital and analog modes.
+    Mode {
+        label: "Mode",
+        scan_index: 110,
+        matrix: (0, 20),
+        geometry: (21.5, 0.0, 1.0, 1.0),
+    }
+    /// Tilde key (`~`).
+    Tilde {
+        label: "~",
+        scan_index: 16,
+        matrix: (1, 0),
+        geometry: (0.0, 1.5, 1.0, 1.0),
+        aliases: ["grave", "backtick", "`"],
+        hid: 0x35,
+        evdev: 41,
+    }
+    /// Number one key (`1`).
+    One {
+        label: "1",
+        scan_index: 17,
+        matrix: (1, 1),
+        geometry: (1.0, 1.5, 1.0, 1.0),
+        hid: 0x1E,
+        evdev: 2,
+    }
+    /// Number two key (`2`).
+    Two {
+        label: "2",
+        scan_index: 18,
+        matrix: (1, 2),
+        geometry: (2.0, 1.5, 1.0, 1.0),
+        hid: 0x1F,
+        evdev: 3,
+    }
+    /// Number three key (`3`).
+    Three {
+        label: "3",
+        scan_index: 19,
+        matrix: (1, 3),
+        geometry: (3.0, 1.5, 1.0, 1.0),
+        hid: 0x20,
+        evdev: 4,
+    }
+    /// Number four key (`4`).
+    Four {
+        label: "4",
+        scan_index: 20,
+        matrix: (1, 4),
+        geometry: (4.0, 1.5, 1.0, 1.0),
+        hid: 0x21,
+        evdev: 5,
+    }
+    /// Number five key (`5`).
+    Five {
+        label: "5",
+        scan_index: 21,
+        matrix: (1, 5),
+        geometry: (5.0, 1.5, 1.0, 1.0),
+        hid: 0x22,
+        evdev: 6,
+    }
+    /// Number six key (`6`).
+    Six {
+        label: "6",
+        scan_index: 22,
+        matrix: (1, 6),
+        geometry: (6.0, 1.5, 1.0, 1.0),
+        hid: 0x23,
+        evdev: 7,
+    }
+    /// Number seven key (`7`).
+    Seven {
+        label: "7",
+        scan_index: 23,
+        matrix: (1, 7),
+        geometry: (7.0, 1.5, 1.0, 1.0),
+        hid: 0x24,
+        evdev: 8,
+    }
+    /// Number eight key (`8`).
+    Eight {
+        label: "8",
+        scan_index: 24,
+        matrix: (1, 8),
+        geometry: (8.0, 1.5, 1.0, 1.0),
+        hid: 0x25,
+        evdev: 9,
+    }
+    /// Number nine key (`9`).
+    Nine {
+        label: "9",
+        scan_index: 25,
+        matrix: (1, 9),
+        geometry: (9.0, 1.5, 1.0, 1.0),
+        hid: 0x26,
+        evdev: 10,
+    }
+    /// Number zero key (`0`).
+    Zero {
+        label: "0",
+        scan_index: 26,
+        matrix: (1, 10),
+        geometry: (10.0, 1.5, 1.0, 1.0),
+        hid: 0x27,
+        evdev: 11,
+    }
+    /// Dash or hyphen key (`-`).
+    Dash {
+        label: "-",
+        scan_index: 27,
+        matrix: (1, 11),
+        geometry: (11.0, 1.5, 1.0, 1.0),
+        aliases: ["minus"],
+        hid: 0x2D,
+        evdev: 12,
+    }
+    /// Equals key (`=`).
+    Equals {
+        label: "=",
+        scan_index: 28,
+        matrix: (1, 12),
+        geometry: (12.0, 1.5, 1.0, 1.0),
+        aliases: ["equal"],
+        hid: 0x2E,
+        evdev: 13,
+    }
+    /// Backspace key. Moves display cursor one position backwards, deleting the character at
+    /// that position and shifting back the text after that position by one position.
+    Backspace {
+        label: "Backspace",
+        scan_index: 29,
+        matrix: (1, 13),
+        geometry: (13.0, 1.5, 2.0, 1.0),
+        aliases: ["bksp", "bs"],
+        hid: 0x2A,
+        evdev: 14,
+    }
+    /// Insert key (`Ins`). Switches between two text entry modes - overtype or insert. Overtype
+    /// mode replaces the character present in the current location. Insert mode inserts a
+    /// character at the current position, forcing all characters past it one position further.
+    Insert {
+        label: "Insert",
+        scan_index: 30,
+        matrix: (1, 14),
+        geometry: (15.25, 1.5, 1.0, 1.0),
+        aliases: ["ins"],
+        hid: 0x49,
+        evdev: 110,
+    }
+    /// Home key. Has the opposite effect of the end key.
+    Home {
+        label: "Home",
+        scan_index: 31,
+        matrix: (1, 15),
+        geometry: (16.25, 1.5, 1.0, 1.0),
+        hid: 0x4A,
+        evdev: 102,
+    }
+    /// Page up key (`Pg Up`). Scrolls up in documents.
+    PageUp {
+        label: "Page Up",
+        scan_index: 61,
+        matrix: (1, 16),
+        geometry: (17.25, 1.5, 1.0, 1.0),
+        aliases: ["pgup", "prior"],
+        hid: 0x4B,
+        evdev: 104,
+    }
+    /// Number lock key (`Num`). Affects the function of the numeric keypad located to the right
+    /// of the main keyboard.
+    NumLock {
+        label: "Num Lock",
+        scan_index: 106,
+        matrix: (1, 17),
+        geometry: (18.5, 1.5, 1.0, 1.0),
+        aliases: ["numlk"],
+        hid: 0x53,
+        evdev: 69,
+    }
+    /// Divide key on the numpad (`/`). Types a forward slash or acts as a divison key in
+    /// calculator applications.
+    NumDivide {
+        label: "/",
+        scan_index: 105,
+        matrix: (1, 18),
+        geometry: (19.5, 1.5, 1.0, 1.0),
+        aliases: ["kp/", "kpdivide", "kpslash"],
+        hid: 0x54,
+        evdev: 98,
+    }
+    /// Multiply key on the numpad (`*`). Types a star or acts as a multiplication key in
+    /// calculator applications.
+    NumMultiply {
+        label: "*",
+        scan_index: 104,
+        matrix: (1, 19),
+        geometry: (20.5, 1.5, 1.0, 1.0),
+        aliases: ["kp*", "kpmultiply", "kpasterisk"],
+        hid: 0x55,
+        evdev: 55,
+    }
+    /// Subtract key on the numpad (`-`). Types a dash or acts as a subtraction key in
+    /// calculator applications.
+    NumSubtract {
+        label: "-",
+        scan_index: 103,
+        matrix: (1, 20),
+        geometry: (21.5, 1.5, 1.0, 1.0),
+        aliases: ["kp-", "kpsubtract", "kpminus"],
+        hid: 0x56,
+        evdev: 74,
+    }
+    /// Tab key. Advances cursor to next tab stop.
+    Tab {
+        label: "Tab",
+        scan_index: 32,
+        matrix: (2, 0),
+        geometry: (0.0, 2.5, 1.5, 1.0),
+        hid: 0x2B,
+        evdev: 15,
+    }
+    /// Letter `q` key.
+    Q {
+        label: "Q",
+        scan_index: 33,
+        matrix: (2, 1),
+        geometry: (1.5, 2.5, 1.0, 1.0),
+        hid: 0x14,
+        evdev: 16,
+    }
+    /// Letter `w` key.
+    W {
+        label: "W",
+        scan_index: 34,
+        matrix: (2, 2),
+        geometry: (2.5, 2.5, 1.0, 1.0),
+        hid: 0x1A,
+        evdev: 17,
+    }
+    /// Letter `e` key.
+    E {
+        label: "E",
+        scan_index: 35,
+        matrix: (2, 3),
+        geometry: (3.5, 2.5, 1.0, 1.0),
+        hid: 0x08,
+        evdev: 18,
+    }
+    /// Letter `r` key.
+    R {
+        label: "R",
+        scan_index: 36,
+        matrix: (2, 4),
+        geometry: (4.5, 2.5, 1.0, 1.0),
+        hid: 0x15,
+        evdev: 19,
+    }
+    /// Letter `t` key.
+    T {
+        label: "T",
+        scan_index: 37,
+        matrix: (2, 5),
+        geometry: (5.5, 2.5, 1.0, 1.0),
+        hid: 0x17,
+        evdev: 20,
+    }
+    /// Letter `y` key.
+    Y {
+        label: "Y",
+        scan_index: 38,
+        matrix: (2, 6),
+        geometry: (6.5, 2.5, 1.0, 1.0),
+        hid: 0x1C,
+        evdev: 21,
+    }
+    /// Letter `u` key.
+    U {
+        label: "U",
+        scan_index: 39,
+        matrix: (2, 7),
+        geometry: (7.5, 2.5, 1.0, 1.0),
+        hid: 0x18,
+        evdev: 22,
+    }
+    /// Letter `i` key.
+    I {
+        label: "I",
+        scan_index: 40,
+        matrix: (2, 8),
+        geometry: (8.5, 2.5, 1.0, 1.0),
+        hid: 0x0C,
+        evdev: 23,
+    }
+    /// Letter `o` key.
+    O {
+        label: "O",
+        scan_index: 41,
+        matrix: (2, 9),
+        geometry: (9.5, 2.5, 1.0, 1.0),
+        hid: 0x12,
+        evdev: 24,
+    }
+    /// Letter `p` key.
+    P {
+        label: "P",
+        scan_index: 42,
+        matrix: (2, 10),
+        geometry: (10.5, 2.5, 1.0, 1.0),
+        hid: 0x13,
+        evdev: 25,
+    }
+    /// Left square bracket key (`[`).
+    LeftBracket {
+        label: "[",
+        scan_index: 43,
+        matrix: (2, 11),
+        geometry: (11.5, 2.5, 1.0, 1.0),
+        aliases: ["lbracket"],
+        hid: 0x2F,
+        evdev: 26,
+    }
+    /// Right square bracket key (`]`).
+    RightBracket {
+        label: "]",
+        scan_index: 44,
+        matrix: (2, 12),
+        geometry: (12.5, 2.5, 1.0, 1.0),
+        aliases: ["rbracket"],
+        hid: 0x30,
+        evdev: 27,
+    }
+    /// Backslash key (`\`). Only exists on ANSI layouts, where it is above the return key.
+    ///
+    /// Shares its scan index (45) with `ISO1`, as a keyboard only has one of the two keys. The
+    /// scan index converts to `Backslash` with `from_scan_index`, as ANSI is the default layout,
+    /// and to the key that the layout has with `from_scan_index_for`, which is how an
+    /// `AnalogReader` reads it.
+    Backslash {
+        label: "\\",
+        scan_index: 45,
+        scan_index_shared_with: ISO1,
+        matrix: (2, 13),
+        geometry: (13.5, 2.5, 1.5, 1.0),
+        layout: Ansi,
+        aliases: ["bslash"],
+        hid: 0x31,
+        evdev: 43,
+    }
+    /// Delete key (`Del`). Deletes the character in the position after the cursor.
+    Delete {
+        label: "Delete",
+        scan_index: 46,
+        matrix: (2, 14),
+        geometry: (15.25, 2.5, 1.0, 1.0),
+        aliases: ["del"],
+        hid: 0x4C,
+        evdev: 111,
+    }
+    /// End key. Has the opposite effect of the home key.
+    End {
+        label: "End",
+        scan_index: 47,
+        matrix: (2, 15),
+        geometry: (16.25, 2.5, 1.0, 1.0),
+        hid: 0x4D,
+        evdev: 107,
+    }
+    /// Page down key (`Pg Dn`). Scrolls down in documents.
+    PageDown {
+        label: "Page Down",
+        scan_index: 62,
+        matrix: (2, 16),
+        geometry: (17.25, 2.5, 1.0, 1.0),
+        aliases: ["pgdn", "pgdown", "next"],
+        hid: 0x4E,
+        evdev: 109,
+    }
+    /// Number seven key (`7`) on the numpad.
+    NumSeven {
+        label: "7",
+        scan_index: 102,
+        matrix: (2, 17),
+        geometry: (18.5, 2.5, 1.0, 1.0),
+        aliases: ["kp7", "num7", "numpad7"],
+        hid: 0x5F,
+        evdev: 71,
+    }
+    /// Number eight key (`8`) on the numpad.
+    NumEight {
+        label: "8",
+        scan_index: 101,
+        matrix: (2, 18),
+        geometry: (19.5, 2.5, 1.0, 1.0),
+        aliases: ["kp8", "num8", "numpad8"],
+        hid: 0x60,
+        evdev: 72,
+    }
+    /// Number nine key (`9`) on the numpad.
+    NumNine {
+        label: "9",
+        scan_index: 100,
+        matrix: (2, 19),
+        geometry: (20.5, 2.5, 1.0, 1.0),
+        aliases: ["kp9", "num9", "numpad9"],
+        hid: 0x61,
+        evdev: 73,
+    }
+    /// Addition key on the numpad (`+`). Types a plus or acts as a addition key in calculator
+    /// applications.
+    NumAddition {
+        label: "+",
+        scan_index: 99,
+        matrix: (2, 20),
+        geometry: (21.5, 2.5, 1.0, 2.0),
+        aliases: ["kp+", "kpadd", "kpplus"],
+        hid: 0x57,
+        evdev: 78,
+    }
+    /// Capitalization lock key. Causes all letters in latin-based scripts to be generated in
+    /// capitals.
+    CapsLock {
+        label: "Caps Lock",
+        scan_index: 48,
+        matrix: (3, 0),
+        geometry: (0.0, 3.5, 1.75, 1.0),
+        aliases: ["caps", "capslk"],
+        hid: 0x39,
+        evdev: 58,
+    }
+    /// Letter `a` key.
+    A {
+        label: "A",
+        scan_index: 49,
+        matrix: (3, 1),
+        geometry: (1.75, 3.5, 1.0, 1.0),
+        hid: 0x04,
+        evdev: 30,
+    }
+    /// Letter `s` key.
+    S {
+        label: "S",
+        scan_index: 50,
+        matrix: (3, 2),
+        geometry: (2.75, 3.5, 1.0, 1.0),
+        hid: 0x16,
+        evdev: 31,
+    }
+    /// Letter `d` key.
+    D {
+        label: "D",
+        scan_index: 51,
+        matrix: (3, 3),
+        geometry: (3.75, 3.5, 1.0, 1.0),
+        hid: 0x07,
+        evdev: 32,
+    }
+    /// Letter `f` key.
+    F {
+        label: "F",
+        scan_index: 52,
+        matrix: (3, 4),
+        geometry: (4.75, 3.5, 1.0, 1.0),
+        hid: 0x09,
+        evdev: 33,
+    }
+    /// Letter `g` key.
+    G {
+        label: "G",
+        scan_index: 53,
+        matrix: (3, 5),
+        geometry: (5.75, 3.5, 1.0, 1.0),
+        hid: 0x0A,
+        evdev: 34,
+    }
+    /// Letter `h` key.
+    H {
+        label: "H",
+        scan_index: 54,
+        matrix: (3, 6),
+        geometry: (6.75, 3.5, 1.0, 1.0),
+        hid: 0x0B,
+        evdev: 35,
+    }
+    /// Letter `j` key.
+    J {
+        label: "J",
+        scan_index: 56,
+        matrix: (3, 7),
+        geometry: (7.75, 3.5, 1.0, 1.0),
+        hid: 0x0D,
+        evdev: 36,
+    }
+    /// Letter `k` key.
+    K {
+        label: "K",
+        scan_index: 57,
+        matrix: (3, 8),
+        geometry: (8.75, 3.5, 1.0, 1.0),
+        hid: 0x0E,
+        evdev: 37,
+    }
+    /// Letter `l` key.
+    L {
+        label: "L",
+        scan_index: 58,
+        matrix: (3, 9),
+        geometry: (9.75, 3.5, 1.0, 1.0),
+        hid: 0x0F,
+        evdev: 38,
+    }
+    /// Semi-colon key (`;`).
+    SemiColon {
+        label: ";",
+        scan_index: 59,
+        matrix: (3, 10),
+        geometry: (10.75, 3.5, 1.0, 1.0),
+        hid: 0x33,
+        evdev: 39,
+    }
+    /// Apostrophe key (`'`).
+    Apostrophe {
+        label: "'",
+        scan_index: 55,
+        matrix: (3, 11),
+        geometry: (11.75, 3.5, 1.0, 1.0),
+        aliases: ["quote"],
+        hid: 0x34,
+        evdev: 40,
+    }
+    /// Represents a key that only exists on ISO layouts, where it is positioned to the left of
+    /// the return key. On UK layouts, this is a pound (`#`) key. On ANSI layouts, the backslash
+    /// key is above the return key instead.
+    ///
+    /// Shares its scan index (45) with `Backslash` (see `Key::Backslash`).
+    ISO1 {
+        label: "Non-US #",
+        scan_index: 45,
+        matrix: (3, 12),
+        geometry: (12.75, 3.5, 1.0, 1.0),
+        layout: Iso,
+        aliases: ["nonushash"],
+        hid: 0x32,
+        evdev: 43,
+    }
+    /// Return (or enter) key. Spans two rows on ISO layouts.
+    Return {
+        label: "Return",
+        scan_index: 60,
+        matrix: (3, 13),
+        geometry: (12.75, 3.5, 2.25, 1.0),
+        iso_geometry: (13.5, 2.5, 1.5, 2.0, cutout: (13.5, 3.5, 0.25, 1.0)),
+        aliases: ["enter", "ret"],
+        hid: 0x28,
+        evdev: 28,
+    }
+    /// Number four key (`4`) on the numpad.
+    NumFour {
+        label: "4",
+        scan_index: 98,
+        matrix: (3, 17),
+        geometry: (18.5, 3.5, 1.0, 1.0),
+        aliases: ["kp4", "num4", "numpad4"],
+        hid: 0x5C,
+        evdev: 75,
+    }
+    /// Number five key (`5`) on the numpad.
+    NumFive {
+        label: "5",
+        scan_index: 97,
+        matrix: (3, 18),
+        geometry: (19.5, 3.5, 1.0, 1.0),
+        aliases: ["kp5", "num5", "numpad5"],
+        hid: 0x5D,
+        evdev: 76,
+    }
+    /// Number six key (`6`) on the numpad.
+    NumSix {
+        label: "6",
+        scan_index: 96,
+        matrix: (3, 19),
+        geometry: (20.5, 3.5, 1.0, 1.0),
+        aliases: ["kp6", "num6", "numpad6"],
+        hid: 0x5E,
+        evdev: 77,
+    }
+    /// Left shift modifier key. Used to type capital letters and other alternate "upper"
+    /// characters.
+    LeftShift {
+        label: "Left Shift",
+        scan_index: 64,
+        matrix: (4, 0),
+        geometry: (0.0, 4.5, 2.25, 1.0),
+        iso_geometry: (0.0, 4.5, 1.25, 1.0),
+        aliases: ["shift", "lshift", "shiftl"],
+        hid: 0xE1,
+        evdev: 42,
+    }
+    /// Represents a key that only exists on ISO layouts, where it is positioned to the right of
+    /// the shorter left shift key. On UK layouts, this is a backslash key.
+    ISO2 {
+        label: "Non-US \\",
+        scan_index: 87,
+        matrix: (4, 1),
+        geometry: (1.25, 4.5, 1.0, 1.0),
+        layout: Iso,
+        aliases: ["nonusbackslash"],
+        hid: 0x64,
+        evdev: 86,
+    }
+    /// Letter `z` key.
+    Z {
+        label: "Z",
+        scan_index: 65,
+        matrix: (4, 2),
+        geometry: (2.25, 4.5, 1.0, 1.0),
+        hid: 0x1D,
+        evdev: 44,
+    }
+    /// Letter `x` key.
+    X {
+        label: "X",
+        scan_index: 66,
+        matrix: (4, 3),
+        geometry: (3.25, 4.5, 1.0, 1.0),
+        hid: 0x1B,
+        evdev: 45,
+    }
+    /// Letter `c` key.
+    C {
+        label: "C",
+        scan_index: 67,
+        matrix: (4, 4),
+        geometry: (4.25, 4.5, 1.0, 1.0),
+        hid: 0x06,
+        evdev: 46,
+    }
+    /// Letter `v` key.
+    V {
+        label: "V",
+        scan_index: 68,
+        matrix: (4, 5),
+        geometry: (5.25, 4.5, 1.0, 1.0),
+        hid: 0x19,
+        evdev: 47,
+    }
+    /// Letter `b` key.
+    B {
+        label: "B",
+        scan_index: 69,
+        matrix: (4, 6),
+        geometry: (6.25, 4.5, 1.0, 1.0),
+        hid: 0x05,
+        evdev: 48,
+    }
+    /// Letter `n` key.
+    N {
+        label: "N",
+        scan_index: 70,
+        matrix: (4, 7),
+        geometry: (7.25, 4.5, 1.0, 1.0),
+        hid: 0x11,
+        evdev: 49,
+    }
+    /// Letter `m` key.
+    M {
+        label: "M",
+        scan_index: 71,
+        matrix: (4, 8),
+        geometry: (8.25, 4.5, 1.0, 1.0),
+        hid: 0x10,
+        evdev: 50,
+    }
+    /// Comma key (`,`).
+    Comma {
+        label: "Comma",
+        scan_index: 72,
+        matrix: (4, 9),
+        geometry: (9.25, 4.5, 1.0, 1.0),
+        aliases: [","],
+        hid: 0x36,
+        evdev: 51,
+    }
+    /// Period key (`.`).
+    Period {
+        label: "Period",
+        scan_index: 73,
+        matrix: (4, 10),
+        geometry: (10.25, 4.5, 1.0, 1.0),
+        aliases: [".", "dot"],
+        hid: 0x37,
+        evdev: 52,
+    }
+    /// Forward slash key (`/`).
+    ForwardSlash {
+        label: "/",
+        scan_index: 74,
+        matrix: (4, 11),
+        geometry: (11.25, 4.5, 1.0, 1.0),
+        aliases: ["/", "slash"],
+        hid: 0x38,
+        evdev: 53,
+    }
+    /// Right shift modifier key. Performs the same function as left shift.
+    RightShift {
+        label: "Right Shift",
+        scan_index: 75,
+        matrix: (4, 13),
+        geometry: (12.25, 4.5, 2.75, 1.0),
+        aliases: ["rshift", "shiftr"],
+        hid: 0xE5,
+        evdev: 54,
+    }
+    /// Up arrow key. Moves the cursor in the upwards direction.
+    UpArrow {
+        label: "Up Arrow",
+        scan_index: 63,
+        matrix: (4, 15),
+        geometry: (16.25, 4.5, 1.0, 1.0),
+        aliases: ["up"],
+        hid: 0x52,
+        evdev: 103,
+    }
+    /// Number one key (`1`) on the numpad.
+    NumOne {
+        label: "1",
+        scan_index: 90,
+        matrix: (4, 17),
+        geometry: (18.5, 4.5, 1.0, 1.0),
+        aliases: ["kp1", "num1", "numpad1"],
+        hid: 0x59,
+        evdev: 79,
+    }
+    /// Number two key (`2`) on the numpad.
+    NumTwo {
+        label: "2",
+        scan_index: 91,
+        matrix: (4, 18),
+        geometry: (19.5, 4.5, 1.0, 1.0),
+        aliases: ["kp2", "num2", "numpad2"],
+        hid: 0x5A,
+        evdev: 80,
+    }
+    /// Number three key (`3`) on the numpad.
+    NumThree {
+        label: "3",
+        scan_index: 92,
+        matrix: (4, 19),
+        geometry: (20.5, 4.5, 1.0, 1.0),
+        aliases: ["kp3", "num3", "numpad3"],
+        hid: 0x5B,
+        evdev: 81,
+    }
+    /// Return (or enter) key on the numpad. Performs the same function as the normal return key.
+    NumReturn {
+        label: "Return",
+        scan_index: 93,
+        matrix: (4, 20),
+        geometry: (21.5, 4.5, 1.0, 2.0),
+        aliases: ["kpenter", "kpreturn", "numenter"],
+        hid: 0x58,
+        evdev: 96,
+    }
+    /// Left control modifier key. Performs a special operation when pressed in conjunction with
+    /// another key.
+    LeftControl {
+        label: "Left Control",
+        scan_index: 80,
+        matrix: (5, 0),
+        geometry: (0.0, 5.5, 1.25, 1.0),
+        aliases: ["ctrl", "lctrl", "ctrll", "control", "lcontrol", "controll"],
+        hid: 0xE0,
+        evdev: 29,
+    }
+    /// Left mod (or Windows) modifier key. Normally invokes the operating system's start menu.
+    LeftMod {
+        label: "Left Mod",
+        scan_index: 81,
+        matrix: (5, 1),
+        geometry: (1.25, 5.5, 1.25, 1.0),
+        aliases: [
+            "lmod", "win", "lwin", "winl", "super", "lsuper", "superl", "meta", "lmeta", "metal",
+            "gui", "lgui", "cmd",
+        ],
+        hid: 0xE3,
+        evdev: 125,
+    }
+    /// Left alt modifier key. Used to change (alternate) the function of other pressed keys.
+    LeftAlt {
+        label: "Left Alt",
+        scan_index: 82,
+        matrix: (5, 2),
+        geometry: (2.5, 5.5, 1.25, 1.0),
+        aliases: ["alt", "lalt", "altl"],
+        hid: 0xE2,
+        evdev: 56,
+    }
+    /// Space key (` `).
+    Space {
+        label: "Space",
+        scan_index: 83,
+        matrix: (5, 6),
+        geometry: (3.75, 5.5, 6.25, 1.0),
+        aliases: ["spacebar"],
+        hid: 0x2C,
+        evdev: 57,
+    }
+    /// Right alt modifier key. Performs the same function as left alt.
+    RightAlt {
+        label: "Right Alt",
+        scan_index: 84,
+        matrix: (5, 10),
+        geometry: (10.0, 5.5, 1.25, 1.0),
+        aliases: ["ralt", "altr", "altgr"],
+        hid: 0xE6,
+        evdev: 100,
+    }
+    /// Right mod modifier key. Performs the same function as left mod.
+    RightMod {
+        label: "Right Mod",
+        scan_index: 85,
+        matrix: (5, 11),
+        geometry: (11.25, 5.5, 1.25, 1.0),
+        aliases: ["rmod", "rwin", "winr", "rsuper", "superr", "rmeta", "metar", "rgui"],
+        hid: 0xE7,
+        evdev: 126,
+    }
+    /// Function key (`Fn`). Performs an alternative operation for some keys, normally defined
+    /// by the keyboard and indicated by symbols on the key.
+    Fn {
+        label: "Fn",
+        scan_index: 86,
+        matrix: (5, 12),
+        geometry: (12.5, 5.5, 1.25, 1.0),
+    }
+    /// Right control modifier key. Performs the same function as left control.
+    RightControl {
+        label: "Right Control",
+        scan_index: 79,
+        matrix: (5, 13),
+        geometry: (13.75, 5.5, 1.25, 1.0),
+        aliases: ["rctrl", "ctrlr", "rcontrol", "controlr"],
+        hid: 0xE4,
+        evdev: 97,
+    }
+    /// Left arrow key. Moves the cursor in the left direction.
+    LeftArrow {
+        label: "Left Arrow",
+        scan_index: 76,
+        matrix: (5, 14),
+        geometry: (15.25, 5.5, 1.0, 1.0),
+        aliases: ["left"],
+        hid: 0x50,
+        evdev: 105,
+    }
+    /// Down arrow key. Moves the cursor in the down direction.
+    DownArrow {
+        label: "Down Arrow",
+        scan_index: 77,
+        matrix: (5, 15),
+        geometry: (16.25, 5.5, 1.0, 1.0),
+        aliases: ["down"],
+        hid: 0x51,
+        evdev: 108,
+    }
+    /// Right arrow key. Moves the cursor in the right direction.
+    RightArrow {
+        label: "Right Arrow",
+        scan_index: 78,
+        matrix: (5, 16),
+        geometry: (17.25, 5.5, 1.0, 1.0),
+        aliases: ["right"],
+        hid: 0x4F,
+        evdev: 106,
+    }
+    /// Number zero key (`0`) on the numpad.
+    NumZero {
+        label: "0",
+        scan_index: 95,
+        matrix: (5, 18),
+        geometry: (18.5, 5.5, 2.0, 1.0),
+        aliases: ["kp0", "num0", "numpad0"],
+        hid: 0x62,
+        evdev: 82,
+    }
+    /// Delete key (`Del`) on the numpad. Performs the same function as the normal delete key.
+    NumDelete {
+        label: "Delete",
+        scan_index: 94,
+        matrix: (5, 19),
+        geometry: (20.5, 5.5, 1.0, 1.0),
+        aliases: ["kp.", "kpdelete", "kpdel", "kpdecimal"],
+        hid: 0x63,
+        evdev: 83,
+    }
+}
+
+lazy_static! {
+    /// Every key, sorted by scan index once rather than on every call to `Key::iter_scan_order`.
+    /// The sort is stable, so keys that share a scan index stay in matrix order.
+    static ref SCAN_ORDER: Vec<Key> = {
+        let mut keys = Key::ALL.to_vec();
+        keys.sort_by_key(|key| key.info().scan_index());
+        keys
+    };
+}
+
+impl Key {
+    /// How many keys are there?
+    pub const COUNT: usize = Key::ALL.len();
+
+    /// Return an iterator over every key, in matrix order (row by row, from left to right).
+    ///
+    /// ```rust
+    /// use wooting_sdk::Key;
+    ///
+    /// let keys: Vec<Key> = Key::iter().take(3).collect();
+    /// assert_eq!(keys, vec![Key::Escape, Key::F1, Key::F2]);
+    /// assert_eq!(Key::iter().count(), Key::COUNT);
+    /// ```
+    pub fn iter() -> impl Iterator<Item = Key> {
+        Key::ALL.iter().copied()
+    }
+
+    /// Return an iterator over every key, in order of scan index. Keys that share a scan index
+    /// are in matrix order.
+    ///
+    /// ```rust
+    /// use wooting_sdk::{IntoScanIndex, Key};
+    ///
+    /// let indices: Vec<u8> = Key::iter_scan_order().map(|key| key.into_scan_index()).collect();
+    /// assert!(indices.windows(2).all(|pair| pair[0] <= pair[1]));
+    /// assert_eq!(indices.len(), Key::COUNT);
+    /// ```
+    pub fn iter_scan_order() -> impl Iterator<Item = Key> {
+        SCAN_ORDER.iter().copied()
+    }
+
+    /// Return the index of the key in `Key::ALL`. Indices are dense, from zero up to (but not
+    /// including) `Key::COUNT`, so they can be used to index arrays with an element per key.
+    ///
+    /// ```rust
+    /// use wooting_sdk::Key;
+    ///
+    /// for (index, key) in Key::iter().enumerate() {
+    ///     assert_eq!(key.index(), index);
+    ///     assert_eq!(Key::ALL[key.index()], key);
+    /// }
+    /// ```
+    pub fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Return the metadata of the key.
+    ///
+    /// ```rust
+    /// use wooting_sdk::Key;
+    ///
+    /// assert_eq!(Key::Apostrophe.info().scan_index(), 55);
+    /// ```
+    pub fn info(self) -> &'static KeyInfo {
+        &KEY_INFO[self.index()]
+    }
+
+    /// Return the name of the key, which is the same as the name of its variant.
+    ///
+    /// ```rust
+    /// use wooting_sdk::Key;
+    ///
+    /// assert_eq!(Key::LeftShift.name(), "LeftShift");
+    /// ```
+    pub fn name(self) -> &'static str {
+        self.info().name()
+    }
+
+    /// Return the USB HID usage ID of the key on the keyboard/keypad usage page (`0x07`), or
+    /// `None` for the keys that are handled by the keyboard itself (`A1`, `A2`, `A3`, `Mode` and
+    /// `Fn`).
+    ///
+    /// ```rust
+    /// use wooting_sdk::Key;
+    ///
+    /// assert_eq!(Key::A.hid_usage(), Some(0x04));
+    /// assert_eq!(Key::NumSeven.hid_usage(), Some(0x5F));
+    /// assert_eq!(Key::ISO1.hid_usage(), Some(0x32));
+    /// assert_eq!(Key::Mode.hid_usage(), None);
+    /// assert_eq!(Key::Fn.hid_usage(), None);
+    ///
+    /// // Every key with a usage ID converts back from it.
+    /// for key in Key::iter() {
+    ///     if let Some(usage) = key.hid_usage() {
+    ///         assert_eq!(Key::from_hid_usage(usage), Some(key));
+    ///     }
+    /// }
+    /// assert_eq!(Key::iter().filter_map(Key::hid_usage).count(), Key::COUNT - 5);
+    /// ```
+    pub fn hid_usage(self) -> Option<u16> {
+        self.info().hid_usage()
+    }
+
+    /// Return the Linux evdev keycode (`KEY_*` in `input-event-codes.h`) of the key, or `None`
+    /// for the keys that are handled by the keyboard itself (`A1`, `A2`, `A3`, `Mode` and `Fn`).
+    ///
+    /// Linux reports `ISO1` as `KEY_BACKSLASH`, the same as `Backslash`, as a keyboard only
+    /// has one of the two keys.
+    ///
+    /// ```rust
+    /// use wooting_sdk::Key;
+    ///
+    /// assert_eq!(Key::Escape.evdev_code(), Some(1)); // KEY_ESC
+    /// assert_eq!(Key::LeftMod.evdev_code(), Some(125)); // KEY_LEFTMETA
+    /// assert_eq!(Key::ISO1.evdev_code(), Key::Backslash.evdev_code());
+    /// assert_eq!(Key::ISO2.evdev_code(), Some(86)); // KEY_102ND
+    /// assert_eq!(Key::A1.evdev_code(), None);
+    ///
+    /// // Every key except `ISO1` converts back from its keycode.
+    /// for key in Key::iter().filter(|&key| key != Key::ISO1) {
+    ///     if let Some(code) = key.evdev_code() {
+    ///         assert_eq!(Key::from_evdev_code(code), Some(key));
+    ///     }
+    /// }
+    /// assert_eq!(Key::iter().filter_map(Key::evdev_code).count(), Key::COUNT - 5);
+    /// ```
+    pub fn evdev_code(self) -> Option<u16> {
+        self.info().evdev_code()
+    }
+
+    /// Return the key that corresponds to the provided Linux evdev keycode (`KEY_*` in
+    /// `input-event-codes.h`), if any. `KEY_BACKSLASH` is always `Backslash`, never `ISO1`.
+    ///
+    /// ```rust
+    /// use wooting_sdk::Key;
+    ///
+    /// assert_eq!(Key::from_evdev_code(30), Some(Key::A)); // KEY_A
+    /// assert_eq!(Key::from_evdev_code(43), Some(Key::Backslash)); // KEY_BACKSLASH
+    /// assert_eq!(Key::from_evdev_code(115), None); // KEY_VOLUMEUP
+    /// ```
+    pub fn from_evdev_code(code: u16) -> Option<Key> {
+        // Unlike the other conversions, this is not a match generated from the table, as
+        // `Backslash` and `ISO1` share a keycode. Keys are in matrix order, so `Backslash` wins.
+        Key::iter().find(|key| key.evdev_code() == Some(code))
+    }
+}
+
+impl Display for Key {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "{}", self.info().label())
+    }
+}
+
+impl IntoMatrixRowColumn for Key {
+    /// Returns a tuple `(row, column)` that represents the matrix row and column of the key.
+    fn into_matrix_row_and_column(&self) -> (u8, u8) {
+        self.info().matrix_row_and_column()
+    }
+}
+
+impl IntoScanIndex for Key {
+    /// Returns the scan index of the key, as reported by the Analog SDK.
+    fn into_scan_index(&self) -> u8 {
+        self.info().scan_index()
+    }
+}
@@ -0,0 +1,204 @@
+//! Contains a map with a value for every key, stored in a fixed-size array.
+
+use super::{IntoMatrixRowColumn, Key, COLUMNS, COMPONENTS, ROWS};
+
+use std::fmt;
+use std::hash::{Hash, Hasher};
+use std::ops::{Index, IndexMut};
+
+/// Represents a map with a value for every key, such as a threshold, color or counter per key.
+/// Values are stored in an array indexed by `Key::index`, so maps never allocate, and are `Copy`
+/// when their values are.
+///
+/// ```rust
+/// use wooting_sdk::{Key, KeyMap};
+///
+/// // Count how many times each key has been pressed..
+/// let mut presses = KeyMap::new(0u32);
+/// presses[Key::A] += 1;
+/// presses[Key::A] += 1;
+/// presses[Key::Escape] += 1;
+///
+/// // ..and iterate over the counts in matrix order.
+/// let pressed: Vec<_> = presses.iter().filter(|&(_, &count)| count > 0).collect();
+/// assert_eq!(pressed, vec![(Key::Escape, &1), (Key::A, &2)]);
+/// ```
+#[derive(Clone, Copy)]
+pub struct KeyMap<T> {
+    values: [T; Key::COUNT],
+}
+
+impl<T: Copy> KeyMap<T> {
+    /// Create a map with the same value for every key.
+    pub fn new(value: T) -> Self {
+        KeyMap {
+            values: [value; Key::COUNT],
+        }
+    }
+
+    /// Create a map with the value returned by the function for each key.
+    ///
+    /// ```rust
+    /// use wooting_sdk::{Key, KeyMap, KeySet};
+    ///
+    /// let is_letter = KeyMap::from_fn(|key| KeySet::LETTERS.contains(key));
+    /// assert!(is_letter[Key::Q]);
+    /// assert!(!is_letter[Key::Tab]);
+    /// ```
+    pub fn from_fn<F: FnMut(Key) -> T>(mut f: F) -> Self {
+        let mut map = KeyMap::new(f(Key::ALL[0]));
+        for &key in &Key::ALL[1..] {
+            map[key] = f(key);
+        }
+        map
+    }
+
+    /// Return a map with the value returned by the function for the value of each key.
+    pub fn map<U: Copy, F: FnMut(Key, T) -> U>(&self, mut f: F) -> KeyMap<U> {
+        KeyMap::from_fn(|key| f(key, self[key]))
+    }
+}
+
+impl<T> KeyMap<T> {
+    /// Return an iterator over every key and its value, in matrix order.
+    pub fn iter(&self) -> impl Iterator<Item = (Key, &T)> {
+        Key::iter().zip(self.values.iter())
+    }
+
+    /// Return an iterator over every key and a mutable reference to its value, in matrix order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (Key, &mut T)> {
+        Key::iter().zip(self.values.iter_mut())
+    }
+
+    /// Return an iterator over the value of every key, in matrix order.
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.values.iter()
+    }
+}
+
+impl<T: Copy + From<(u8, u8, u8)> + Into<(u8, u8, u8)>> KeyMap<T> {
+    /// Create a map of colors from the flattened buffer that the RGB SDK's `array_set_full`
+    /// takes, in which each key has three consecutive components (red, green and blue) at the
+    /// position of its matrix row and column. Colors are `(red, green, blue)` tuples or any type
+    /// that converts to and from them, such as `rgb::Color`.
+    ///
+    /// ```rust
+    /// use wooting_sdk::{Key, KeyMap};
+    ///
+    /// let mut colors = KeyMap::new((0, 0, 0));
+    /// colors[Key::Escape] = (255, 0, 0);
+    /// colors[Key::Mode] = (0, 0, 255);
+    ///
+    /// // Escape is the first column of the first row, and Mode is the last.
+    /// let buffer = colors.to_rgb_buffer();
+    /// assert_eq!(&buffer[..3], &[255, 0, 0]);
+    /// assert_eq!(&buffer[60..63], &[0, 0, 255]);
+    /// assert!(KeyMap::from_rgb_buffer(&buffer) == colors);
+    /// ```
+    pub fn from_rgb_buffer(buffer: &[u8; COMPONENTS * COLUMNS * ROWS]) -> Self {
+        KeyMap::from_fn(|key| {
+            let index = rgb_buffer_index(&key);
+            T::from((buffer[index], buffer[index + 1], buffer[index + 2]))
+        })
+    }
+
+    /// Return the flattened buffer that the RGB SDK's `array_set_full` takes, with the color of
+    /// each key at the position of its matrix row and column. Positions without a key are
+    /// black.
+    pub fn to_rgb_buffer(&self) -> [u8; COMPONENTS * COLUMNS * ROWS] {
+        let mut buffer = [0; COMPONENTS * COLUMNS * ROWS];
+        for (key, &color) in self.iter() {
+            let (red, green, blue) = color.into();
+            let index = rgb_buffer_index(&key);
+            buffer[index] = red;
+            buffer[index + 1] = green;
+            buffer[index + 2] = blue;
+        }
+        buffer
+    }
+}
+
+/// Return the index of the first component of the key's color in the flattened buffer that the
+/// RGB SDK's `array_set_full` takes.
+pub(crate) fn rgb_buffer_index<K: IntoMatrixRowColumn>(key: &K) -> usize {
+    let (row, column) = key.into_matrix_row_and_column();
+    (row as usize) * (COLUMNS * COMPONENTS) + (column as usize) * COMPONENTS
+}
+
+impl<T: Copy + Default> Default for KeyMap<T> {
+    fn default() -> Self {
+        KeyMap::new(T::default())
+    }
+}
+
+impl<T> Index<Key> for KeyMap<T> {
+    type Output = T;
+
+    fn index(&self, key: Key) -> &T {
+        &self.values[key.index()]
+    }
+}
+
+impl<T> IndexMut<Key> for KeyMap<T> {
+    fn index_mut(&mut self, key: Key) -> &mut T {
+        &mut self.values[key.index()]
+    }
+}
+
+impl<T: fmt::Debug> fmt::Debug for KeyMap<T> {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq> PartialEq for KeyMap<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.values[..] == other.values[..]
+    }
+}
+
+impl<T: Eq> Eq for KeyMap<T> {}
+
+impl<T: Hash> Hash for KeyMap<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.values[..].hash(state)
+    }
+}
+
+/// Serializes a map as a map from key names to values.
+///
+/// ```rust
+/// use wooting_sdk::{Key, KeyMap};
+///
+/// let mut thresholds = KeyMap::new(0u8);
+/// thresholds[Key::W] = 100;
+/// let json = serde_json::to_string(&thresholds).unwrap();
+/// assert!(json.starts_with(r#"{"Escape":0,"F1":0,"#));
+/// assert!(serde_json::from_str::<KeyMap<u8>>(&json).unwrap() == thresholds);
+///
+/// // Keys that are missing are deserialized with the default value.
+/// let thresholds: KeyMap<u8> = serde_json::from_str(r#"{"W":100}"#).unwrap();
+/// assert_eq!(thresholds[Key::W], 100);
+/// assert_eq!(thresholds[Key::A], 0);
+/// ```
+#[cfg(feature = "serde")]
+impl<T: serde::Serialize> serde::Serialize for KeyMap<T> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.iter())
+    }
+}
+
+/// Deserializes a map from a map from keys to values. Keys that are missing have the default
+/// value.
+#[cfg(feature = "serde")]
+impl<'de, T: serde::Deserialize<'de> + Copy + Default> serde::Deserialize<'de> for KeyMap<T> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values: std::collections::HashMap<Key, T> =
+            serde::Deserialize::deserialize(deserializer)?;
+        let mut map = KeyMap::default();
+        for (key, value) in values {
+            map[key] = value;
+        }
+        Ok(map)
+    }
+}
@@ -0,0 +1,320 @@
+//! Contains a compact set of keys, along with the groups of keys that effects commonly target.
+
+use super::Key;
+
+use std::fmt;
+use std::iter::FromIterator;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, Sub, SubAssign};
+
+/// Declares a `KeySet` constant that contains the given keys.
+macro_rules! key_set {
+    ($($key:ident),* $(,)?) => {
+        KeySet {
+            bits: 0 $(| 1 << Key::$key as u128)*,
+        }
+    };
+}
+
+/// Represents a set of keys, stored as a single bit per key. Sets are `Copy`, never allocate and
+/// iterate in matrix order.
+///
+/// ```rust
+/// use wooting_sdk::{Key, KeySet};
+///
+/// // Every key fits in a set..
+/// assert!(Key::COUNT <= 128);
+/// assert_eq!(KeySet::ALL.len(), Key::COUNT);
+///
+/// // ..and the predefined groups can be combined.
+/// let movement = KeySet::WASD | KeySet::ARROWS;
+/// assert_eq!(movement.len(), 8);
+/// assert!(movement.contains(Key::UpArrow));
+///
+/// let letters_without_wasd = KeySet::LETTERS - KeySet::WASD;
+/// assert_eq!(letters_without_wasd.len(), 22);
+/// assert!((KeySet::LETTERS & KeySet::NUMPAD).is_empty());
+///
+/// let keys: KeySet = vec![Key::Q, Key::A].into_iter().collect();
+/// assert_eq!(keys.iter().collect::<Vec<_>>(), vec![Key::Q, Key::A]);
+/// ```
+#[derive(Clone, Copy, Default, Hash, Eq, PartialEq)]
+pub struct KeySet {
+    bits: u128,
+}
+
+impl KeySet {
+    /// No keys.
+    pub const EMPTY: KeySet = KeySet { bits: 0 };
+
+    /// Every key.
+    pub const ALL: KeySet = KeySet {
+        bits: (1 << Key::COUNT) - 1,
+    };
+
+    /// Function keys, `F1` to `F12`.
+    pub const FUNCTION_ROW: KeySet = key_set![F1, F2, F3, F4, F5, F6, F7, F8, F9, F10, F11, F12];
+
+    /// Number keys above the letters, `1` to `0`.
+    pub const NUMBER_ROW: KeySet =
+        key_set![One, Two, Three, Four, Five, Six, Seven, Eight, Nine, Zero];
+
+    /// Letter keys, `A` to `Z`, named as on a US keyboard.
+    pub const LETTERS: KeySet =
+        key_set![A, B, C, D, E, F, G, H, I, J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z];
+
+    /// Shift, control, alt and mod keys, on both sides of the keyboard.
+    pub const MODIFIERS: KeySet = key_set![
+        LeftShift,
+        RightShift,
+        LeftControl,
+        RightControl,
+        LeftAlt,
+        RightAlt,
+        LeftMod,
+        RightMod,
+    ];
+
+    /// Arrow keys.
+    pub const ARROWS: KeySet = key_set![UpArrow, LeftArrow, DownArrow, RightArrow];
+
+    /// Navigation cluster above the arrow keys: insert, home, page up, delete, end and page down.
+    pub const NAVIGATION: KeySet = key_set![Insert, Home, PageUp, Delete, End, PageDown];
+
+    /// Every key on the numpad, including num lock.
+    pub const NUMPAD: KeySet = key_set![
+        NumLock,
+        NumDivide,
+        NumMultiply,
+        NumSubtract,
+        NumSeven,
+        NumEight,
+        NumNine,
+        NumAddition,
+        NumFour,
+        NumFive,
+        NumSix,
+        NumOne,
+        NumTwo,
+        NumThree,
+        NumReturn,
+        NumZero,
+        NumDelete,
+    ];
+
+    /// `W`, `A`, `S` and `D`, the movement keys in many games.
+    pub const WASD: KeySet = key_set![W, A, S, D];
+
+    /// Keys that are specific to Wooting keyboards and handled by the keyboard itself: the analog
+    /// profile keys (`A1`, `A2` and `A3`), `Mode` and `Fn`.
+    pub const WOOTING: KeySet = key_set![A1, A2, A3, Mode, Fn];
+
+    /// Create an empty set.
+    pub fn new() -> Self {
+        KeySet::EMPTY
+    }
+
+    /// Return the bit of the key.
+    fn bit(key: Key) -> u128 {
+        1 << key.index()
+    }
+
+    /// Is the key in the set?
+    pub fn contains(self, key: Key) -> bool {
+        self.bits & Self::bit(key) != 0
+    }
+
+    /// Add the key to the set. Returns `true` if the key was not already in the set.
+    pub fn insert(&mut self, key: Key) -> bool {
+        let inserted = !self.contains(key);
+        self.bits |= Self::bit(key);
+        inserted
+    }
+
+    /// Remove the key from the set. Returns `true` if the key was in the set.
+    pub fn remove(&mut self, key: Key) -> bool {
+        let removed = self.contains(key);
+        self.bits &= !Self::bit(key);
+        removed
+    }
+
+    /// Return how many keys are in the set.
+    pub fn len(self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Is the set empty?
+    pub fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    /// Return a set with the keys that are in either set.
+    pub fn union(self, other: KeySet) -> KeySet {
+        KeySet {
+            bits: self.bits | other.bits,
+        }
+    }
+
+    /// Return a set with the keys that are in both sets.
+    pub fn intersection(self, other: KeySet) -> KeySet {
+        KeySet {
+            bits: self.bits & other.bits,
+        }
+    }
+
+    /// Return a set with the keys that are in this set but not in the other set.
+    pub fn difference(self, other: KeySet) -> KeySet {
+        KeySet {
+            bits: self.bits & !other.bits,
+        }
+    }
+
+    /// Is every key in this set also in the other set?
+    ///
+    /// ```rust
+    /// use wooting_sdk::KeySet;
+    ///
+    /// assert!(KeySet::WASD.is_subset(KeySet::LETTERS));
+    /// assert!(!KeySet::LETTERS.is_subset(KeySet::WASD));
+    /// ```
+    pub fn is_subset(self, other: KeySet) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    /// Return an iterator over the keys in the set, in matrix order.
+    pub fn iter(self) -> KeySetIter {
+        KeySetIter { bits: self.bits }
+    }
+}
+
+impl fmt::Debug for KeySet {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl From<Key> for KeySet {
+    fn from(key: Key) -> Self {
+        KeySet {
+            bits: Self::bit(key),
+        }
+    }
+}
+
+impl FromIterator<Key> for KeySet {
+    fn from_iter<I: IntoIterator<Item = Key>>(iter: I) -> Self {
+        let mut set = KeySet::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl Extend<Key> for KeySet {
+    fn extend<I: IntoIterator<Item = Key>>(&mut self, iter: I) {
+        for key in iter {
+            let _ = self.insert(key);
+        }
+    }
+}
+
+impl IntoIterator for KeySet {
+    type Item = Key;
+    type IntoIter = KeySetIter;
+
+    fn into_iter(self) -> KeySetIter {
+        self.iter()
+    }
+}
+
+impl BitOr for KeySet {
+    type Output = KeySet;
+
+    fn bitor(self, other: KeySet) -> KeySet {
+        self.union(other)
+    }
+}
+
+impl BitOrAssign for KeySet {
+    fn bitor_assign(&mut self, other: KeySet) {
+        *self = self.union(other);
+    }
+}
+
+impl BitAnd for KeySet {
+    type Output = KeySet;
+
+    fn bitand(self, other: KeySet) -> KeySet {
+        self.intersection(other)
+    }
+}
+
+impl BitAndAssign for KeySet {
+    fn bitand_assign(&mut self, other: KeySet) {
+        *self = self.intersection(other);
+    }
+}
+
+impl Sub for KeySet {
+    type Output = KeySet;
+
+    fn sub(self, other: KeySet) -> KeySet {
+        self.difference(other)
+    }
+}
+
+impl SubAssign for KeySet {
+    fn sub_assign(&mut self, other: KeySet) {
+        *self = self.difference(other);
+    }
+}
+
+/// Serializes a set as a sequence of key names, in matrix order.
+///
+/// ```rust
+/// use wooting_sdk::KeySet;
+///
+/// let json = serde_json::to_string(&KeySet::WASD).unwrap();
+/// assert_eq!(json, r#"["W","A","S","D"]"#);
+/// assert_eq!(serde_json::from_str::<KeySet>(&json).unwrap(), KeySet::WASD);
+/// ```
+#[cfg(feature = "serde")]
+impl serde::Serialize for KeySet {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.iter())
+    }
+}
+
+/// Deserializes a set from a sequence of keys.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for KeySet {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let keys: Vec<Key> = serde::Deserialize::deserialize(deserializer)?;
+        Ok(keys.into_iter().collect())
+    }
+}
+
+/// Iterator over the keys in a `KeySet`, in matrix order.
+#[derive(Clone, Copy, Debug)]
+pub struct KeySetIter {
+    bits: u128,
+}
+
+impl Iterator for KeySetIter {
+    type Item = Key;
+
+    fn next(&mut self) -> Option<Key> {
+        if self.bits == 0 {
+            return None;
+        }
+
+        let index = self.bits.trailing_zeros() as usize;
+        self.bits &= self.bits - 1;
+        Some(Key::ALL[index])
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for KeySetIter {}
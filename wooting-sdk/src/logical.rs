@@ -0,0 +1,415 @@
+//! Contains the logical layouts of keyboards, which decide the character that each key types.
+
+use super::{Key, PhysicalLayout};
+
+use std::fmt::{self, Display};
+
+/// Represents the modifier keys that must be held for a key to type a character.
+///
+/// ```rust
+/// use wooting_sdk::{Key, Modifiers};
+///
+/// assert_eq!(Modifiers::NONE.keys(), vec![]);
+/// assert_eq!(Modifiers::SHIFT.keys(), vec![Key::LeftShift]);
+/// assert_eq!(Modifiers::ALT_GR.keys(), vec![Key::RightAlt]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Modifiers {
+    /// Must either shift key be held?
+    pub shift: bool,
+    /// Must the right alt (AltGr) key be held?
+    pub alt_gr: bool,
+}
+
+impl Modifiers {
+    /// No modifiers.
+    pub const NONE: Modifiers = Modifiers {
+        shift: false,
+        alt_gr: false,
+    };
+
+    /// Only shift.
+    pub const SHIFT: Modifiers = Modifiers {
+        shift: true,
+        alt_gr: false,
+    };
+
+    /// Only right alt (AltGr).
+    pub const ALT_GR: Modifiers = Modifiers {
+        shift: false,
+        alt_gr: true,
+    };
+
+    /// Return the keys that must be held for these modifiers, such as to light them up alongside
+    /// the key that types a character.
+    pub fn keys(self) -> Vec<Key> {
+        let mut keys = Vec::new();
+        if self.shift {
+            keys.push(Key::LeftShift);
+        }
+        if self.alt_gr {
+            keys.push(Key::RightAlt);
+        }
+        keys
+    }
+
+    /// Return the index of these modifiers in the characters of a `LayoutEntry`, or `None` if no
+    /// layout types anything with this combination.
+    fn level(self) -> Option<usize> {
+        LEVELS.iter().position(|&level| level == self)
+    }
+}
+
+/// Modifiers for each character of a `LayoutEntry`.
+const LEVELS: [Modifiers; 3] = [Modifiers::NONE, Modifiers::SHIFT, Modifiers::ALT_GR];
+
+/// Represents the logical layout of a keyboard, which decides the character that each key types
+/// (as configured in the operating system).
+///
+/// ```rust
+/// use wooting_sdk::{Key, LogicalLayout, Modifiers};
+///
+/// // The same character is typed by different keys on different layouts..
+/// assert_eq!(
+///     LogicalLayout::UsQwerty.key_for_char('z'),
+///     Some((Key::Z, Modifiers::NONE))
+/// );
+/// assert_eq!(
+///     LogicalLayout::GermanQwertz.key_for_char('z'),
+///     Some((Key::Y, Modifiers::NONE))
+/// );
+///
+/// // ..with different modifiers..
+/// assert_eq!(
+///     LogicalLayout::UsQwerty.key_for_char('@'),
+///     Some((Key::Two, Modifiers::SHIFT))
+/// );
+/// assert_eq!(
+///     LogicalLayout::GermanQwertz.key_for_char('@'),
+///     Some((Key::Q, Modifiers::ALT_GR))
+/// );
+///
+/// // ..and some characters cannot be typed at all.
+/// assert_eq!(LogicalLayout::UsQwerty.key_for_char('ß'), None);
+/// ```
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LogicalLayout {
+    /// US QWERTY.
+    UsQwerty,
+    /// German QWERTZ (T1), with dead keys.
+    GermanQwertz,
+}
+
+impl LogicalLayout {
+    /// Return the physical layout that keyboards with this logical layout normally have.
+    ///
+    /// ```rust
+    /// use wooting_sdk::{LogicalLayout, PhysicalLayout};
+    ///
+    /// assert_eq!(LogicalLayout::UsQwerty.physical_layout(), PhysicalLayout::Ansi);
+    /// assert_eq!(LogicalLayout::GermanQwertz.physical_layout(), PhysicalLayout::Iso);
+    /// ```
+    pub fn physical_layout(self) -> PhysicalLayout {
+        match self {
+            LogicalLayout::UsQwerty => PhysicalLayout::Ansi,
+            LogicalLayout::GermanQwertz => PhysicalLayout::Iso,
+        }
+    }
+
+    /// Return the key, and the modifiers that must be held with it, that types the character, or
+    /// `None` if the character cannot be typed with a single key press on this layout. Characters
+    /// that are only typed with a dead key (such as `^` on German QWERTZ) cannot be typed with a
+    /// single key press.
+    ///
+    /// Characters are typed with keys outside of the numpad. Tab, return (`'\n'`) and space are
+    /// typed by their keys.
+    ///
+    /// ```rust
+    /// use wooting_sdk::{Key, LogicalLayout, Modifiers};
+    ///
+    /// let layout = LogicalLayout::GermanQwertz;
+    /// let keys: Option<Vec<_>> = "Straße!".chars().map(|c| layout.key_for_char(c)).collect();
+    /// assert_eq!(
+    ///     keys,
+    ///     Some(vec![
+    ///         (Key::S, Modifiers::SHIFT),
+    ///         (Key::T, Modifiers::NONE),
+    ///         (Key::R, Modifiers::NONE),
+    ///         (Key::A, Modifiers::NONE),
+    ///         (Key::Dash, Modifiers::NONE),
+    ///         (Key::E, Modifiers::NONE),
+    ///         (Key::One, Modifiers::SHIFT),
+    ///     ])
+    /// );
+    ///
+    /// assert_eq!(layout.key_for_char('^'), None);
+    /// assert_eq!(layout.key_for_char('\n'), Some((Key::Return, Modifiers::NONE)));
+    /// ```
+    pub fn key_for_char(self, c: char) -> Option<(Key, Modifiers)> {
+        self.entries().iter().find_map(|&(key, chars)| {
+            chars
+                .iter()
+                .position(|&typed| typed == Some(c))
+                .map(|level| (key, LEVELS[level]))
+        })
+    }
+
+    /// Return the character that the key types when the modifiers are held, or `None` if the key
+    /// does not type a character on this layout (such as modifier and dead keys).
+    ///
+    /// ```rust
+    /// use wooting_sdk::{Key, LogicalLayout, Modifiers};
+    ///
+    /// for &layout in &[LogicalLayout::UsQwerty, LogicalLayout::GermanQwertz] {
+    ///     // Every character that a key types is typed by that key, unless an earlier key types
+    ///     // it too.
+    ///     for key in Key::iter() {
+    ///         for &modifiers in &[Modifiers::NONE, Modifiers::SHIFT, Modifiers::ALT_GR] {
+    ///             if let Some(c) = layout.char_for_key(key, modifiers) {
+    ///                 let (typed_by, _) = layout.key_for_char(c).unwrap();
+    ///                 assert!(typed_by.index() <= key.index());
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let layout = LogicalLayout::GermanQwertz;
+    /// assert_eq!(layout.char_for_key(Key::E, Modifiers::ALT_GR), Some('€'));
+    /// assert_eq!(layout.char_for_key(Key::LeftShift, Modifiers::NONE), None);
+    /// ```
+    pub fn char_for_key(self, key: Key, modifiers: Modifiers) -> Option<char> {
+        let level = modifiers.level()?;
+        self.entries()
+            .iter()
+            .find(|&&(entry, _)| entry == key)
+            .and_then(|&(_, chars)| chars[level])
+    }
+
+    /// Return the characters typed by each key on this layout.
+    fn entries(self) -> &'static [LayoutEntry] {
+        match self {
+            LogicalLayout::UsQwerty => US_QWERTY,
+            LogicalLayout::GermanQwertz => GERMAN_QWERTZ,
+        }
+    }
+
+    /// Return the labels of the keys that are printed differently on this layout than on a US
+    /// keyboard.
+    fn labels(self) -> &'static [(Key, &'static str)] {
+        match self {
+            LogicalLayout::UsQwerty => &[],
+            LogicalLayout::GermanQwertz => GERMAN_QWERTZ_LABELS,
+        }
+    }
+}
+
+impl Key {
+    /// Return the label of the key, as printed on a keyboard with the given layout. `Display`
+    /// prints the label of the key on a US keyboard.
+    ///
+    /// ```rust
+    /// use wooting_sdk::{Key, LogicalLayout};
+    ///
+    /// assert_eq!(Key::Y.label(LogicalLayout::UsQwerty), "Y");
+    /// assert_eq!(Key::Y.label(LogicalLayout::GermanQwertz), "Z");
+    /// assert_eq!(Key::Delete.label(LogicalLayout::GermanQwertz), "Entf");
+    /// assert_eq!(Key::NumDivide.label(LogicalLayout::GermanQwertz), "/");
+    ///
+    /// // Labels of keys that type a letter are that letter in uppercase (except for `ß`, which
+    /// // has no single uppercase letter)..
+    /// for &layout in &[LogicalLayout::UsQwerty, LogicalLayout::GermanQwertz] {
+    ///     for key in Key::iter() {
+    ///         if let Some(c) = layout.char_for_key(key, Default::default()) {
+    ///             if c.is_alphabetic() && c != 'ß' {
+    ///                 assert_eq!(key.label(layout), c.to_uppercase().to_string());
+    ///             }
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// // ..and labels on US keyboards are the same as `Display`.
+    /// for key in Key::iter() {
+    ///     assert_eq!(key.label(LogicalLayout::UsQwerty), key.to_string());
+    /// }
+    /// ```
+    pub fn label(self, layout: LogicalLayout) -> &'static str {
+        layout
+            .labels()
+            .iter()
+            .find(|&&(key, _)| key == self)
+            .map_or_else(|| self.info().label(), |&(_, label)| label)
+    }
+}
+
+impl Default for LogicalLayout {
+    /// Returns US QWERTY.
+    fn default() -> Self {
+        LogicalLayout::UsQwerty
+    }
+}
+
+impl Display for LogicalLayout {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            LogicalLayout::UsQwerty => write!(fmt, "US QWERTY"),
+            LogicalLayout::GermanQwertz => write!(fmt, "German QWERTZ"),
+        }
+    }
+}
+
+/// A key and the characters that it types with no modifiers, with shift and with AltGr.
+type LayoutEntry = (Key, [Option<char>; 3]);
+
+/// Declares the characters typed by each key on a layout, in matrix order. Each key is followed
+/// by the characters that it types with no modifiers, with shift and with AltGr, where `_` is no
+/// character.
+macro_rules! layout {
+    (@char _) => { None };
+    (@char $c:literal) => { Some($c) };
+    ($($key:ident: $base:tt $shift:tt $alt_gr:tt,)*) => {
+        &[$((Key::$key, [layout!(@char $base), layout!(@char $shift), layout!(@char $alt_gr)]),)*]
+    };
+}
+
+const US_QWERTY: &[LayoutEntry] = layout! {
+    Tilde: '`' '~' _,
+    One: '1' '!' _,
+    Two: '2' '@' _,
+    Three: '3' '#' _,
+    Four: '4' '$' _,
+    Five: '5' '%' _,
+    Six: '6' '^' _,
+    Seven: '7' '&' _,
+    Eight: '8' '*' _,
+    Nine: '9' '(' _,
+    Zero: '0' ')' _,
+    Dash: '-' '_' _,
+    Equals: '=' '+' _,
+    Tab: '\t' _ _,
+    Q: 'q' 'Q' _,
+    W: 'w' 'W' _,
+    E: 'e' 'E' _,
+    R: 'r' 'R' _,
+    T: 't' 'T' _,
+    Y: 'y' 'Y' _,
+    U: 'u' 'U' _,
+    I: 'i' 'I' _,
+    O: 'o' 'O' _,
+    P: 'p' 'P' _,
+    LeftBracket: '[' '{' _,
+    RightBracket: ']' '}' _,
+    Backslash: '\\' '|' _,
+    A: 'a' 'A' _,
+    S: 's' 'S' _,
+    D: 'd' 'D' _,
+    F: 'f' 'F' _,
+    G: 'g' 'G' _,
+    H: 'h' 'H' _,
+    J: 'j' 'J' _,
+    K: 'k' 'K' _,
+    L: 'l' 'L' _,
+    SemiColon: ';' ':' _,
+    Apostrophe: '\'' '"' _,
+    Return: '\n' _ _,
+    Z: 'z' 'Z' _,
+    X: 'x' 'X' _,
+    C: 'c' 'C' _,
+    V: 'v' 'V' _,
+    B: 'b' 'B' _,
+    N: 'n' 'N' _,
+    M: 'm' 'M' _,
+    Comma: ',' '<' _,
+    Period: '.' '>' _,
+    ForwardSlash: '/' '?' _,
+    Space: ' ' _ _,
+};
+
+const GERMAN_QWERTZ: &[LayoutEntry] = layout! {
+    Tilde: _ '°' _,
+    One: '1' '!' _,
+    Two: '2' '"' '²',
+    Three: '3' '§' '³',
+    Four: '4' '$' _,
+    Five: '5' '%' _,
+    Six: '6' '&' _,
+    Seven: '7' '/' '{',
+    Eight: '8' '(' '[',
+    Nine: '9' ')' ']',
+    Zero: '0' '=' '}',
+    Dash: 'ß' '?' '\\',
+    Tab: '\t' _ _,
+    Q: 'q' 'Q' '@',
+    W: 'w' 'W' _,
+    E: 'e' 'E' '€',
+    R: 'r' 'R' _,
+    T: 't' 'T' _,
+    Y: 'z' 'Z' _,
+    U: 'u' 'U' _,
+    I: 'i' 'I' _,
+    O: 'o' 'O' _,
+    P: 'p' 'P' _,
+    LeftBracket: 'ü' 'Ü' _,
+    RightBracket: '+' '*' '~',
+    A: 'a' 'A' _,
+    S: 's' 'S' _,
+    D: 'd' 'D' _,
+    F: 'f' 'F' _,
+    G: 'g' 'G' _,
+    H: 'h' 'H' _,
+    J: 'j' 'J' _,
+    K: 'k' 'K' _,
+    L: 'l' 'L' _,
+    SemiColon: 'ö' 'Ö' _,
+    Apostrophe: 'ä' 'Ä' _,
+    ISO1: '#' '\'' _,
+    Return: '\n' _ _,
+    ISO2: '<' '>' '|',
+    Z: 'y' 'Y' _,
+    X: 'x' 'X' _,
+    C: 'c' 'C' _,
+    V: 'v' 'V' _,
+    B: 'b' 'B' _,
+    N: 'n' 'N' _,
+    M: 'm' 'M' 'µ',
+    Comma: ',' ';' _,
+    Period: '.' ':' _,
+    ForwardSlash: '-' '_' _,
+    Space: ' ' _ _,
+};
+
+const GERMAN_QWERTZ_LABELS: &[(Key, &str)] = &[
+    (Key::PrintScreen, "Druck"),
+    (Key::ScrollLock, "Rollen"),
+    (Key::Tilde, "^"),
+    (Key::Dash, "ß"),
+    (Key::Equals, "´"),
+    (Key::Backspace, "Rücktaste"),
+    (Key::Insert, "Einfg"),
+    (Key::Home, "Pos1"),
+    (Key::PageUp, "Bild ↑"),
+    (Key::NumLock, "Num"),
+    (Key::Y, "Z"),
+    (Key::LeftBracket, "Ü"),
+    (Key::RightBracket, "+"),
+    (Key::Delete, "Entf"),
+    (Key::End, "Ende"),
+    (Key::PageDown, "Bild ↓"),
+    (Key::CapsLock, "Feststell"),
+    (Key::SemiColon, "Ö"),
+    (Key::Apostrophe, "Ä"),
+    (Key::ISO1, "#"),
+    (Key::Return, "Eingabe"),
+    (Key::LeftShift, "Umschalt"),
+    (Key::ISO2, "<"),
+    (Key::Z, "Y"),
+    (Key::ForwardSlash, "-"),
+    (Key::RightShift, "Umschalt"),
+    (Key::NumReturn, "Eingabe"),
+    (Key::LeftControl, "Strg"),
+    (Key::Space, "Leertaste"),
+    (Key::RightAlt, "Alt Gr"),
+    (Key::RightControl, "Strg"),
+    (Key::NumDelete, "Entf"),
+];
@@ -0,0 +1,193 @@
+//! Contains the models of Wooting keyboard and which keys each of them has.
+
+use super::{FromMatrixRowColumn, IntoMatrixRowColumn, Key, COLUMNS};
+
+use std::fmt::{self, Display};
+
+/// Represents a model of Wooting keyboard.
+///
+/// ```rust
+/// use wooting_sdk::{Key, KeyboardModel};
+///
+/// // The Wooting One has no numpad..
+/// assert!(!Key::NumLock.is_available_on(KeyboardModel::WootingOne));
+/// // ..but the Wooting Two does.
+/// assert!(Key::NumLock.is_available_on(KeyboardModel::WootingTwo));
+/// ```
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum KeyboardModel {
+    /// Wooting One. A tenkeyless keyboard, without the numpad or the analog profile and mode keys
+    /// that are above it.
+    WootingOne,
+    /// Wooting Two. A full-size keyboard, with every key in the matrix.
+    WootingTwo,
+}
+
+impl KeyboardModel {
+    /// Return how many columns of the keyboard matrix this model has.
+    ///
+    /// ```rust
+    /// use wooting_sdk::KeyboardModel;
+    ///
+    /// assert_eq!(KeyboardModel::WootingOne.columns(), 17);
+    /// assert_eq!(KeyboardModel::WootingTwo.columns(), 21);
+    /// ```
+    pub fn columns(self) -> usize {
+        match self {
+            KeyboardModel::WootingOne => 17,
+            KeyboardModel::WootingTwo => COLUMNS,
+        }
+    }
+
+    /// Return an iterator over every key that this model has, in matrix order.
+    ///
+    /// ```rust
+    /// use wooting_sdk::{Key, KeyboardModel};
+    ///
+    /// assert_eq!(KeyboardModel::WootingOne.keys().count(), 89);
+    /// assert_eq!(KeyboardModel::WootingTwo.keys().count(), Key::COUNT);
+    /// ```
+    pub fn keys(self) -> impl Iterator<Item = Key> {
+        Key::iter().filter(move |key| key.is_available_on(self))
+    }
+
+    /// Return an iterator over every key that this model has with the given layout, in matrix
+    /// order.
+    ///
+    /// ```rust
+    /// use wooting_sdk::{Key, KeyboardModel, PhysicalLayout};
+    ///
+    /// let model = KeyboardModel::WootingTwo;
+    /// assert_eq!(model.layout_keys(PhysicalLayout::Ansi).count(), Key::COUNT - 2);
+    /// assert_eq!(model.layout_keys(PhysicalLayout::Iso).count(), Key::COUNT - 1);
+    /// assert!(model.layout_keys(PhysicalLayout::Iso).any(|key| key == Key::ISO1));
+    /// ```
+    pub fn layout_keys(self, layout: PhysicalLayout) -> impl Iterator<Item = Key> {
+        self.keys()
+            .filter(move |key| key.is_available_on_layout(layout))
+    }
+
+    /// Does this model have a key at the given matrix row and column?
+    ///
+    /// ```rust
+    /// use wooting_sdk::KeyboardModel;
+    ///
+    /// // Escape..
+    /// assert!(KeyboardModel::WootingOne.has_key_at(0, 0));
+    /// // ..the gap between Escape and F1..
+    /// assert!(!KeyboardModel::WootingOne.has_key_at(0, 1));
+    /// // ..and the Mode key.
+    /// assert!(!KeyboardModel::WootingOne.has_key_at(0, 20));
+    /// assert!(KeyboardModel::WootingTwo.has_key_at(0, 20));
+    /// ```
+    pub fn has_key_at(self, row: u8, column: u8) -> bool {
+        Key::from_matrix_row_and_column(row, column).map_or(false, |key| key.is_available_on(self))
+    }
+
+    /// Return the matrix row and column of the key if this model has a key at that position,
+    /// otherwise `Err(WootingError::UnsupportedKey)`.
+    #[cfg(any(feature = "analog", feature = "rgb"))]
+    pub(crate) fn matrix_row_and_column<K: IntoMatrixRowColumn>(
+        self,
+        key: &K,
+    ) -> Result<(u8, u8), super::WootingError> {
+        let (row, column) = key.into_matrix_row_and_column();
+        if self.has_key_at(row, column) {
+            Ok((row, column))
+        } else {
+            Err(super::WootingError::UnsupportedKey {
+                model: self,
+                row,
+                column,
+            })
+        }
+    }
+}
+
+impl Default for KeyboardModel {
+    /// Returns the Wooting Two, which has every key in the matrix.
+    fn default() -> Self {
+        KeyboardModel::WootingTwo
+    }
+}
+
+impl Display for KeyboardModel {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            KeyboardModel::WootingOne => write!(fmt, "Wooting One"),
+            KeyboardModel::WootingTwo => write!(fmt, "Wooting Two"),
+        }
+    }
+}
+
+/// Represents the physical layout of a keyboard, which decides the shape of the return and left
+/// shift keys, and whether the keyboard has the `Backslash` key or the `ISO1` and `ISO2` keys.
+/// Every model of Wooting keyboard is available with either layout.
+///
+/// ```rust
+/// use wooting_sdk::{Key, PhysicalLayout};
+///
+/// // ANSI keyboards have a backslash key above the return key..
+/// assert!(Key::Backslash.is_available_on_layout(PhysicalLayout::Ansi));
+/// assert!(!Key::ISO1.is_available_on_layout(PhysicalLayout::Ansi));
+/// // ..whereas ISO keyboards have a key to the left of the return key instead.
+/// assert!(!Key::Backslash.is_available_on_layout(PhysicalLayout::Iso));
+/// assert!(Key::ISO1.is_available_on_layout(PhysicalLayout::Iso));
+/// ```
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PhysicalLayout {
+    /// ANSI layout, common in the United States. Has a wide left shift key, a single-row return
+    /// key and a backslash key above the return key.
+    Ansi,
+    /// ISO layout, common in Europe. Has a narrow left shift key, a two-row return key and an
+    /// extra key next to each of them.
+    Iso,
+}
+
+impl Default for PhysicalLayout {
+    /// Returns the ANSI layout.
+    fn default() -> Self {
+        PhysicalLayout::Ansi
+    }
+}
+
+impl Display for PhysicalLayout {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            PhysicalLayout::Ansi => write!(fmt, "ANSI"),
+            PhysicalLayout::Iso => write!(fmt, "ISO"),
+        }
+    }
+}
+
+impl Key {
+    /// Does the given model of keyboard have this key?
+    ///
+    /// ```rust
+    /// use wooting_sdk::{Key, KeyboardModel};
+    ///
+    /// assert!(Key::Escape.is_available_on(KeyboardModel::WootingOne));
+    /// assert!(!Key::Mode.is_available_on(KeyboardModel::WootingOne));
+    /// assert!(Key::Mode.is_available_on(KeyboardModel::WootingTwo));
+    /// ```
+    pub fn is_available_on(self, model: KeyboardModel) -> bool {
+        let (_, column) = self.into_matrix_row_and_column();
+        (column as usize) < model.columns()
+    }
+
+    /// Does a keyboard with the given layout have this key?
+    ///
+    /// ```rust
+    /// use wooting_sdk::{Key, PhysicalLayout};
+    ///
+    /// assert!(Key::Escape.is_available_on_layout(PhysicalLayout::Ansi));
+    /// assert!(Key::Escape.is_available_on_layout(PhysicalLayout::Iso));
+    /// assert!(!Key::ISO2.is_available_on_layout(PhysicalLayout::Ansi));
+    /// assert!(Key::ISO2.is_available_on_layout(PhysicalLayout::Iso));
+    /// ```
+    pub fn is_available_on_layout(self, layout: PhysicalLayout) -> bool {
+        self.info().layout().map_or(true, |only| only == layout)
+    }
+}
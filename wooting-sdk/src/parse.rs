@@ -0,0 +1,200 @@
+//! Contains the parsing of keys from strings, such as those in user configuration.
+
+use super::{Key, KeyInfo};
+
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::str::FromStr;
+
+/// Represents an error that can occur when parsing a `Key` from a string that is not the name,
+/// label or alias of any key.
+///
+/// ```rust
+/// use wooting_sdk::Key;
+///
+/// let error = "escpae".parse::<Key>().unwrap_err();
+/// assert_eq!(error.input(), "escpae");
+/// assert_eq!(error.suggestion(), Some(Key::Escape));
+/// assert_eq!(error.to_string(), "unknown key `escpae`, did you mean `Escape`?");
+///
+/// let error = "volume up".parse::<Key>().unwrap_err();
+/// assert_eq!(error.suggestion(), None);
+/// assert_eq!(error.to_string(), "unknown key `volume up`");
+/// ```
+#[derive(Clone, Debug, Hash, Eq, PartialEq)]
+pub struct ParseKeyError {
+    input: String,
+    suggestion: Option<Key>,
+}
+
+impl ParseKeyError {
+    /// Return the string that could not be parsed.
+    pub fn input(&self) -> &str {
+        &self.input
+    }
+
+    /// Return the key whose name, label or alias is closest to the string that could not be
+    /// parsed, if any is close enough to be a likely typo.
+    pub fn suggestion(&self) -> Option<Key> {
+        self.suggestion
+    }
+}
+
+impl Display for ParseKeyError {
+    fn fmt(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(fmt, "unknown key `{}`", self.input)?;
+        match self.suggestion {
+            Some(key) => write!(fmt, ", did you mean `{}`?", key.name()),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Error for ParseKeyError {}
+
+impl FromStr for Key {
+    type Err = ParseKeyError;
+
+    /// Parse a key from its name (`"LeftShift"`), its label (`"Left Shift"`) or one of its
+    /// aliases (`"lshift"`). Case, spaces and underscores are ignored.
+    ///
+    /// Names are preferred over aliases, which are preferred over labels. Labels that are shared
+    /// by more than one key, such as `"7"`, are parsed as the key outside of the numpad.
+    ///
+    /// ```rust
+    /// use wooting_sdk::{Key, KeyInfo};
+    ///
+    /// assert_eq!("Escape".parse(), Ok(Key::Escape));
+    /// assert_eq!("esc".parse(), Ok(Key::Escape));
+    /// assert_eq!("LEFT SHIFT".parse(), Ok(Key::LeftShift));
+    /// assert_eq!("lshift".parse(), Ok(Key::LeftShift));
+    /// assert_eq!("ctrl_l".parse(), Ok(Key::LeftControl));
+    /// assert_eq!("7".parse(), Ok(Key::Seven));
+    /// assert_eq!("kp7".parse(), Ok(Key::NumSeven));
+    ///
+    /// // Every key is parsed from its name and each of its aliases, and every label is parsed as
+    /// // a key with that label.
+    /// for info in KeyInfo::all() {
+    ///     assert_eq!(info.name().parse(), Ok(info.key()));
+    ///     for alias in info.aliases() {
+    ///         assert_eq!(alias.parse(), Ok(info.key()));
+    ///     }
+    ///     assert_eq!(info.label().parse::<Key>().unwrap().info().label(), info.label());
+    /// }
+    /// ```
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        let normalized = normalize(input);
+        let all = KeyInfo::all();
+        all.iter()
+            .find(|info| normalize(info.name()) == normalized)
+            .or_else(|| {
+                all.iter()
+                    .find(|info| info.aliases().contains(&&*normalized))
+            })
+            .or_else(|| {
+                all.iter()
+                    .find(|info| normalize(info.label()) == normalized)
+            })
+            .map(KeyInfo::key)
+            .ok_or_else(|| ParseKeyError {
+                input: input.to_owned(),
+                suggestion: suggest(&normalized),
+            })
+    }
+}
+
+/// Return the string in lowercase, without spaces or underscores.
+fn normalize(input: &str) -> String {
+    input
+        .chars()
+        .filter(|&c| c != ' ' && c != '_')
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+/// Return the key with the name, label or alias that is the fewest edits away from the
+/// normalized input, if it is close enough to be a likely typo.
+fn suggest(normalized: &str) -> Option<Key> {
+    let threshold = (normalized.chars().count() / 3).max(1);
+    KeyInfo::all()
+        .iter()
+        .flat_map(|info| {
+            let names = vec![normalize(info.name()), normalize(info.label())];
+            let aliases = info.aliases().iter().map(|alias| (*alias).to_owned());
+            names
+                .into_iter()
+                .chain(aliases)
+                .map(move |candidate| (edit_distance(normalized, &candidate), info.key()))
+        })
+        .filter(|&(distance, _)| distance <= threshold)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, key)| key)
+}
+
+/// Return the Levenshtein distance between two strings: the number of characters that must be
+/// inserted, deleted or substituted to turn one into the other.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1; b.len() + 1];
+        for (j, &b) in b.iter().enumerate() {
+            let substitution = previous[j] + if a == b { 0 } else { 1 };
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Serializes a key as its name, which is stable across releases.
+///
+/// ```rust
+/// use wooting_sdk::{Key, KeyboardModel, WootingError};
+///
+/// let keys = vec![Key::LeftShift, Key::NumSeven];
+/// let json = serde_json::to_string(&keys).unwrap();
+/// assert_eq!(json, r#"["LeftShift","NumSeven"]"#);
+/// assert_eq!(serde_json::from_str::<Vec<Key>>(&json).unwrap(), keys);
+///
+/// // Keys are deserialized from anything that they can be parsed from..
+/// assert_eq!(serde_json::from_str::<Key>(r#""lshift""#).unwrap(), Key::LeftShift);
+/// // ..and unknown keys are an error.
+/// assert!(serde_json::from_str::<Key>(r#""escpae""#).is_err());
+///
+/// let error = WootingError::UnsupportedKey {
+///     model: KeyboardModel::WootingOne,
+///     row: 4,
+///     column: 17,
+/// };
+/// let json = serde_json::to_string(&error).unwrap();
+/// assert_eq!(serde_json::from_str::<WootingError>(&json).unwrap(), error);
+/// ```
+#[cfg(feature = "serde")]
+impl serde::Serialize for Key {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.name())
+    }
+}
+
+/// Deserializes a key from a string, as with `FromStr`.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Key {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct KeyVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for KeyVisitor {
+            type Value = Key;
+
+            fn expecting(&self, fmt: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(fmt, "the name of a key")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, value: &str) -> Result<Key, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_str(KeyVisitor)
+    }
+}
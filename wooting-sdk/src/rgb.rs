@@ -0,0 +1,1137 @@
+//! Contains functions from Wooting's RGB SDK.
+
+use super::backend::{FfiBackend, RgbBackend, Subscription};
+use super::{
+    FromMatrixRowColumn, IntoMatrixRowColumn, Key, KeyMap, KeySet, KeyboardModel, WootingError,
+};
+
+pub use super::color::{Color, ParseColorError};
+pub use super::frame::{CommitStats, CommitStrategy, RgbFrame};
+
+use std::mem;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Is there a Wooting keyboard connected?
+///
+/// ```rust,no_run
+/// // Assert that a Wooting keyboard is connected..
+/// assert!(wooting_sdk::rgb::is_wooting_keyboard_connected());
+/// ```
+pub fn is_wooting_keyboard_connected() -> bool {
+    FfiBackend.is_connected()
+}
+
+/// Subscribe a callback to be invoked when a keyboard is disconnected, until the returned
+/// subscription is dropped. Currently only happens on a failed write. Any number of callbacks can
+/// be subscribed, and a callback that panics does not stop the others from being invoked.
+///
+/// See [`rgb_disconnected_callback`][example] example for usage.
+///
+/// [example]: https://github.com/davidtwco/rust-wooting-sdk/blob/master/wooting-sdk/examples/rgb_disconnected_callback.rs
+pub fn subscribe_disconnected<F: 'static + Fn() + Send + Sync>(callback: F) -> Subscription {
+    FfiBackend.subscribe_disconnected(Box::new(callback))
+}
+
+/// Set a callback to be invoked when a keyboard is disconnected. Currently only happens on a
+/// failed write.
+///
+/// The callback stays subscribed until the program exits, and a later call subscribes another
+/// callback rather than replacing it.
+#[deprecated(note = "use `subscribe_disconnected`, which can unsubscribe the callback")]
+pub fn set_disconnected_callback<F: 'static + Fn() + Send>(callback: F) {
+    let callback = Mutex::new(callback);
+    mem::forget(subscribe_disconnected(move || {
+        (callback.lock().unwrap_or_else(PoisonError::into_inner))()
+    }));
+}
+
+/// Represents the connected keyboard to perform RGB operations. This struct only exists to
+/// enforce that `reset` is called on drop. Operations are performed through a backend, which
+/// is the [`FfiBackend`][ffi] by default.
+///
+/// A keyboard has exclusive control of the LEDs until it is dropped, so that two keyboards in the
+/// same process cannot overwrite each other's colors. Creating a keyboard while another has
+/// control, whether directly or with a [`Wooting`][wooting] context, returns
+/// `Err(WootingError::InUse)`.
+///
+/// Keyboards are aware of their model, which is the Wooting Two unless otherwise specified.
+/// Setting the color of a key that the model does not have is an error.
+///
+/// Keyboards keep a copy of the last frame that was committed with `commit` or the array
+/// functions. When the keyboard is unplugged and plugged back in, that frame is replayed by
+/// `poll_reconnect`, or else before the next write (see `set_restore_on_reconnect`).
+///
+/// The RGB SDK cannot read colors back from the keyboard, so keyboards also track the color that
+/// each key is showing after every write (see `get_key_color` and `snapshot`). `commit` uses this
+/// to only send the keys that changed.
+///
+/// ```rust
+/// use wooting_sdk::{backend::SimulatedKeyboard, rgb::RgbKeyboard, Key, WootingError};
+///
+/// let simulated = SimulatedKeyboard::new();
+/// let mut keyboard = RgbKeyboard::new(simulated.clone()).unwrap();
+///
+/// // Set the A key to white..
+/// assert_eq!(keyboard.direct_set_key(Key::A, (255, 255, 255)), Ok(()));
+/// // ..and check that it was written.
+/// assert_eq!(simulated.displayed().color(Key::A), Some((255, 255, 255)));
+/// assert_eq!(simulated.frames().len(), 1);
+///
+/// // Writes fail while the keyboard is disconnected.
+/// simulated.set_connected(false);
+/// assert_eq!(keyboard.array_update(), Err(WootingError::Disconnected));
+/// ```
+///
+/// [ffi]: ../backend/struct.FfiBackend.html
+/// [wooting]: ../struct.Wooting.html
+#[derive(Debug)]
+pub struct RgbKeyboard<B: RgbBackend = FfiBackend> {
+    backend: B,
+    model: KeyboardModel,
+    auto_update: bool,
+    /// Colors that have been set in the color array, which may not have been committed yet.
+    array: RgbFrame,
+    /// Colors that were in the color array when it was last sent to the keyboard, or `None` if
+    /// nothing has been sent since the keyboard was last reset.
+    committed: Option<RgbFrame>,
+    /// Colors that the keyboard is believed to be showing, or `None` for keys that show their
+    /// original color.
+    displayed: KeyMap<Option<Color>>,
+    restore_on_reconnect: bool,
+    /// Might the backend's color array differ from `array`? Per-key commits and keys that cannot
+    /// be written directly change the colors that are shown without the color array, and the
+    /// backend may or may not have taken a color array that it failed to show. Either way, the
+    /// color array is passed to the backend again before it is next changed or updated.
+    array_unsent: bool,
+    /// Most keys that can change in a commit before the whole frame is sent instead.
+    sparse_threshold: usize,
+    /// Has the keyboard been noticed to be disconnected since the last committed frame was shown?
+    disconnected: Arc<AtomicBool>,
+    _subscription: Subscription,
+}
+
+impl<B: RgbBackend> RgbKeyboard<B> {
+    /// Most keys that can change in a commit before the whole frame is sent instead, unless
+    /// otherwise specified. Each key is a separate write to the keyboard, so beyond a handful of
+    /// keys a single update of the color array is quicker, even though it passes more bytes.
+    pub const DEFAULT_SPARSE_THRESHOLD: usize = 16;
+
+    /// Create a `RgbKeyboard` for a Wooting Two that performs operations through the given
+    /// backend.
+    ///
+    /// Returns `Err(WootingError::InUse)` if another `RgbKeyboard` has control of the LEDs.
+    pub fn new(backend: B) -> Result<Self, WootingError> {
+        Self::with_model(backend, KeyboardModel::default())
+    }
+
+    /// Create a `RgbKeyboard` for the given model of keyboard that performs operations through
+    /// the given backend.
+    ///
+    /// Returns `Err(WootingError::InUse)` if another `RgbKeyboard` has control of the LEDs.
+    ///
+    /// ```rust
+    /// use wooting_sdk::{
+    ///     backend::SimulatedKeyboard, rgb::RgbKeyboard, Key, KeyboardModel, WootingError,
+    /// };
+    ///
+    /// let simulated = SimulatedKeyboard::new();
+    /// let mut keyboard =
+    ///     RgbKeyboard::with_model(simulated.clone(), KeyboardModel::WootingOne).unwrap();
+    /// assert_eq!(keyboard.direct_set_key(Key::Escape, (255, 255, 255)), Ok(()));
+    /// assert_eq!(
+    ///     keyboard.direct_set_key(Key::NumLock, (255, 255, 255)),
+    ///     Err(WootingError::UnsupportedKey {
+    ///         model: KeyboardModel::WootingOne,
+    ///         row: 1,
+    ///         column: 17,
+    ///     })
+    /// );
+    ///
+    /// // Only one keyboard can control the LEDs at a time.
+    /// assert_eq!(RgbKeyboard::new(simulated.clone()).unwrap_err(), WootingError::InUse);
+    /// drop(keyboard);
+    /// assert!(RgbKeyboard::new(simulated).is_ok());
+    /// ```
+    pub fn with_model(backend: B, model: KeyboardModel) -> Result<Self, WootingError> {
+        if !backend.try_claim() {
+            return Err(WootingError::InUse);
+        }
+
+        let disconnected = Arc::new(AtomicBool::new(false));
+        let flag = disconnected.clone();
+        let subscription = backend.subscribe_disconnected(Box::new(move || {
+            flag.store(true, Ordering::SeqCst);
+        }));
+
+        Ok(RgbKeyboard {
+            backend,
+            model,
+            auto_update: false,
+            array: RgbFrame::default(),
+            committed: None,
+            displayed: KeyMap::new(None),
+            restore_on_reconnect: true,
+            array_unsent: false,
+            sparse_threshold: Self::DEFAULT_SPARSE_THRESHOLD,
+            disconnected,
+            _subscription: subscription,
+        })
+    }
+
+    /// Return the model of keyboard that operations are performed on.
+    pub fn model(&self) -> KeyboardModel {
+        self.model
+    }
+
+    /// Set the model of keyboard that operations are performed on.
+    pub fn set_model(&mut self, model: KeyboardModel) {
+        self.model = model;
+    }
+
+    /// Return the backend that operations are performed through.
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Is the last committed frame replayed when the keyboard is plugged back in? Enabled by
+    /// default.
+    pub fn restore_on_reconnect(&self) -> bool {
+        self.restore_on_reconnect
+    }
+
+    /// Set whether the last committed frame is replayed when the keyboard is plugged back in.
+    /// The keyboard cannot tell by itself when it has been plugged back in, so the frame is
+    /// replayed when `poll_reconnect` is called, or else before the first write after the
+    /// keyboard is reconnected.
+    ///
+    /// ```rust
+    /// # fn test() -> Result<(), wooting_sdk::WootingError> {
+    /// use wooting_sdk::{backend::SimulatedKeyboard, rgb::RgbKeyboard, Key};
+    ///
+    /// let simulated = SimulatedKeyboard::new();
+    /// let mut keyboard = RgbKeyboard::new(simulated.clone())?;
+    /// keyboard.array_set_single(Key::A, (255, 0, 0))?;
+    /// keyboard.array_update()?;
+    ///
+    /// // Unplugging the keyboard loses its colors..
+    /// simulated.set_connected(false);
+    /// simulated.set_connected(true);
+    /// assert_eq!(simulated.displayed().color(Key::A), None);
+    ///
+    /// // ..which are replayed before the next write..
+    /// keyboard.direct_set_key(Key::Escape, (0, 0, 255))?;
+    /// assert_eq!(simulated.displayed().color(Key::A), Some((255, 0, 0)));
+    /// assert_eq!(simulated.displayed().color(Key::Escape), Some((0, 0, 255)));
+    ///
+    /// // ..unless that has been turned off.
+    /// keyboard.set_restore_on_reconnect(false);
+    /// simulated.set_connected(false);
+    /// simulated.set_connected(true);
+    /// keyboard.direct_set_key(Key::Escape, (0, 0, 255))?;
+    /// assert_eq!(simulated.displayed().color(Key::A), None);
+    /// # Ok(())
+    /// # }
+    /// # test().unwrap();
+    /// ```
+    pub fn set_restore_on_reconnect(&mut self, restore_on_reconnect: bool) {
+        self.restore_on_reconnect = restore_on_reconnect;
+    }
+
+    /// Return the most keys that can change in a commit before the whole frame is sent instead.
+    pub fn sparse_threshold(&self) -> usize {
+        self.sparse_threshold
+    }
+
+    /// Set the most keys that can change in a commit before the whole frame is sent instead. A
+    /// threshold of zero always sends the whole frame.
+    pub fn set_sparse_threshold(&mut self, sparse_threshold: usize) {
+        self.sparse_threshold = sparse_threshold;
+    }
+
+    /// Replay the last committed frame, such as after the keyboard has been plugged back in. Any
+    /// changes to the color array that have not been committed yet are kept. Does nothing if
+    /// nothing has been committed since the keyboard was last reset.
+    ///
+    /// Returns `Err(WootingError::Disconnected)` if the keyboard is not connected or
+    /// `Err(WootingError::WriteFailed)` if writing to the keyboard failed.
+    pub fn restore(&mut self) -> Result<(), WootingError> {
+        let committed = match self.committed {
+            Some(committed) => committed,
+            None => return Ok(()),
+        };
+
+        let written = self.backend.array_set_full(committed.as_buffer())
+            && (self.auto_update || self.backend.array_update_keyboard());
+        self.check_written(written)?;
+        self.displayed = self.shown_colors(&committed);
+        self.array_unsent = false;
+        // The frame is showing again, so there is nothing left to replay.
+        self.disconnected.store(false, Ordering::SeqCst);
+
+        if self.array != committed {
+            let written = self.backend.array_set_full(self.array.as_buffer());
+            self.check_written(written)?;
+            self.commit_if_auto_updated();
+        }
+        Ok(())
+    }
+
+    /// Replay the last committed frame if the keyboard has been plugged back in since it was
+    /// disconnected, and replaying is enabled (see `set_restore_on_reconnect`). Returns whether
+    /// the keyboard was plugged back in.
+    ///
+    /// The keyboard is noticed to be unplugged when the backend reports it, when a write fails or
+    /// when this finds that the keyboard is not connected, so call this regularly for an
+    /// application that does not write to the keyboard again to get its colors back. The RGB SDK
+    /// may only notice when a write fails, so if the keyboard is unplugged and plugged back in
+    /// between two calls, the frame is not replayed. An application that watches the keyboard
+    /// with a [`ConnectionWatcher`][watcher] can call `restore` when it reports that the keyboard
+    /// was connected instead, as in the [`connection_watcher`][example] example.
+    ///
+    /// Returns `Err(WootingError::Disconnected)` if the keyboard is unplugged again while the
+    /// frame is replayed or `Err(WootingError::WriteFailed)` if writing to the keyboard failed.
+    ///
+    /// ```rust
+    /// # fn test() -> Result<(), wooting_sdk::WootingError> {
+    /// use wooting_sdk::{
+    ///     backend::SimulatedKeyboard,
+    ///     rgb::{Color, RgbKeyboard},
+    ///     Key, KeySet,
+    /// };
+    ///
+    /// let simulated = SimulatedKeyboard::new();
+    /// let mut keyboard = RgbKeyboard::new(simulated.clone())?;
+    /// keyboard.array_fill(KeySet::WASD, Color::RED)?;
+    /// keyboard.array_update()?;
+    ///
+    /// // Unplugging the keyboard loses its colors..
+    /// simulated.set_connected(false);
+    /// assert!(!keyboard.poll_reconnect()?);
+    /// simulated.set_connected(true);
+    /// assert_eq!(simulated.displayed().color(Key::W), None);
+    ///
+    /// // ..which are replayed without another write.
+    /// assert!(keyboard.poll_reconnect()?);
+    /// assert_eq!(simulated.displayed().color(Key::W), Some((255, 0, 0)));
+    /// assert!(!keyboard.poll_reconnect()?);
+    /// # Ok(())
+    /// # }
+    /// # test().unwrap();
+    /// ```
+    ///
+    /// [watcher]: ../struct.ConnectionWatcher.html
+    /// [example]: https://github.com/davidtwco/rust-wooting-sdk/blob/master/wooting-sdk/examples/connection_watcher.rs
+    pub fn poll_reconnect(&mut self) -> Result<bool, WootingError> {
+        if !self.disconnected.load(Ordering::SeqCst) {
+            if !self.is_connected() {
+                // Remember the disconnect, as the backend may not have reported it and the
+                // keyboard may be plugged back in before the next call.
+                self.disconnected.store(true, Ordering::SeqCst);
+            }
+            return Ok(false);
+        }
+
+        let reconnected = self.forget_displayed_if_reconnected();
+        if reconnected && self.restore_on_reconnect {
+            self.restore()?;
+        }
+        Ok(reconnected)
+    }
+
+    /// Replay the last committed frame if the keyboard has been plugged back in since it was
+    /// noticed to be disconnected, and replaying is enabled.
+    fn restore_if_reconnected(&mut self) -> Result<(), WootingError> {
+        if self.forget_displayed_if_reconnected() && self.restore_on_reconnect {
+            self.restore()?;
+        }
+        Ok(())
+    }
+
+    /// Forget the colors that the keyboard was showing if it has been plugged back in since it
+    /// was noticed to be disconnected. Returns whether it was.
+    ///
+    /// Whether the keyboard is connected is only checked once a disconnect has been noticed, so
+    /// that every write to a keyboard that stays connected is a single call to the backend.
+    fn forget_displayed_if_reconnected(&mut self) -> bool {
+        if self.disconnected.load(Ordering::SeqCst) && self.is_connected() {
+            self.disconnected.store(false, Ordering::SeqCst);
+            // Keyboards show their original colors when they are plugged back in.
+            self.displayed = KeyMap::new(None);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Pass the color array to the backend if it was changed by per-key commits, so that
+    /// changes and updates to the array start from the last committed frame.
+    fn send_array_if_unsent(&mut self) -> Result<(), WootingError> {
+        if self.array_unsent {
+            let written = self.backend.array_set_full(self.array.as_buffer());
+            self.check_written(written)?;
+            self.array_unsent = false;
+            self.commit_if_auto_updated();
+        }
+        Ok(())
+    }
+
+    /// Record the color array as committed, and so shown on the keyboard.
+    fn record_commit(&mut self) {
+        let array = self.array;
+        self.committed = Some(array);
+        self.displayed = self.shown_colors(&array);
+    }
+
+    /// Return the colors that the keyboard shows after the frame is sent to it, which are only
+    /// for the keys that the keyboard model has.
+    fn shown_colors(&self, frame: &RgbFrame) -> KeyMap<Option<Color>> {
+        let model = self.model;
+        KeyMap::from_fn(|key| Some(frame[key]).filter(|_| key.is_available_on(model)))
+    }
+
+    /// Record the color array as committed if changes to it are sent to the keyboard
+    /// immediately.
+    fn commit_if_auto_updated(&mut self) {
+        if self.auto_update {
+            self.record_commit();
+        }
+    }
+
+    /// Return the color that the key is showing, or `None` if it shows its original color (or
+    /// the keyboard does not have the key). As the RGB SDK cannot read colors back from the
+    /// keyboard, this is the color of the last successful write to the key.
+    ///
+    /// ```rust
+    /// # fn test() -> Result<(), wooting_sdk::WootingError> {
+    /// use wooting_sdk::{
+    ///     backend::SimulatedKeyboard,
+    ///     rgb::{Color, RgbKeyboard},
+    ///     Key, KeyboardModel,
+    /// };
+    ///
+    /// let simulated = SimulatedKeyboard::new();
+    /// let mut keyboard = RgbKeyboard::new(simulated.clone())?;
+    ///
+    /// keyboard.array_set_single(Key::A, Color::RED)?;
+    /// assert_eq!(keyboard.get_key_color(Key::A), None);
+    /// keyboard.array_update()?;
+    /// assert_eq!(keyboard.get_key_color(Key::A), Some(Color::RED));
+    ///
+    /// keyboard.direct_set_key(Key::B, Color::GREEN)?;
+    /// keyboard.direct_reset_key(Key::A)?;
+    /// assert_eq!(keyboard.get_key_color(Key::A), None);
+    /// assert_eq!(keyboard.get_key_color(Key::B), Some(Color::GREEN));
+    ///
+    /// // The colors that are tracked are those that the keyboard shows..
+    /// let check = |keyboard: &RgbKeyboard<SimulatedKeyboard>| {
+    ///     for key in Key::iter() {
+    ///         let displayed = simulated.displayed().color(key).map(Color::from);
+    ///         assert_eq!(keyboard.get_key_color(key), displayed);
+    ///     }
+    /// };
+    /// check(&keyboard);
+    ///
+    /// // ..including with auto update..
+    /// keyboard.array_auto_update(true);
+    /// keyboard.array_set_full(&[(Key::W, (0, 0, 255))])?;
+    /// check(&keyboard);
+    ///
+    /// // ..and after a reset.
+    /// keyboard.reset_all()?;
+    /// assert_eq!(keyboard.get_key_color(Key::W), None);
+    /// check(&keyboard);
+    ///
+    /// // Keys that the keyboard model does not have never show a color.
+    /// keyboard.set_model(KeyboardModel::WootingOne);
+    /// keyboard.array_update()?;
+    /// assert_eq!(keyboard.get_key_color(Key::Escape), Some(Color::BLACK));
+    /// assert_eq!(keyboard.get_key_color(Key::NumLock), None);
+    /// assert_eq!(keyboard.snapshot()[Key::NumLock], None);
+    /// # Ok(())
+    /// # }
+    /// # test().unwrap();
+    /// ```
+    pub fn get_key_color<K: IntoMatrixRowColumn>(&self, key: K) -> Option<Color> {
+        let (row, column) = key.into_matrix_row_and_column();
+        if !self.model.has_key_at(row, column) {
+            return None;
+        }
+        Key::from_matrix_row_and_column(row, column).and_then(|key| self.displayed[key])
+    }
+
+    /// Return the color that every key is showing, or `None` for keys that show their original
+    /// color (or that the keyboard does not have).
+    pub fn snapshot(&self) -> KeyMap<Option<Color>> {
+        let model = self.model;
+        self.displayed
+            .map(|key, color| color.filter(|_| key.is_available_on(model)))
+    }
+
+    /// Is there a Wooting keyboard connected?
+    pub fn is_connected(&self) -> bool {
+        self.backend.is_connected()
+    }
+
+    /// Subscribe a callback to be invoked when a keyboard is disconnected, until the returned
+    /// subscription is dropped. Currently only happens on a failed write.
+    pub fn subscribe_disconnected<F: 'static + Fn() + Send + Sync>(
+        &self,
+        callback: F,
+    ) -> Subscription {
+        self.backend.subscribe_disconnected(Box::new(callback))
+    }
+
+    /// Return `Ok(())` if a write succeeded, otherwise the reason that it failed:
+    /// `Err(WootingError::Disconnected)` if the keyboard is not connected, or
+    /// `Err(WootingError::WriteFailed)` if it is.
+    fn check_written(&self, written: bool) -> Result<(), WootingError> {
+        if written {
+            Ok(())
+        } else if !self.is_connected() {
+            self.disconnected.store(true, Ordering::SeqCst);
+            Err(WootingError::Disconnected)
+        } else {
+            Err(WootingError::WriteFailed)
+        }
+    }
+
+    /// Set the color of a single key. This will not influence the keyboard color array. Use
+    /// this function for simple amplifications, like a notification. Use the array functions
+    /// if you want to change the entire keyboard.
+    ///
+    /// The numpad of a Wooting Two and the keys above it cannot be written directly, so for
+    /// those keys the colors that the keyboard is showing are sent as a whole, with the key
+    /// changed. Changes to the color array that have not been applied are not shown. Keys that
+    /// show their original color are set to their color in the last committed frame, or to
+    /// black if nothing has been committed since the keyboard was last reset.
+    ///
+    /// Returns `Err(WootingError::UnsupportedKey)` if the keyboard model does not have the key,
+    /// `Err(WootingError::Disconnected)` if the keyboard is not connected or
+    /// `Err(WootingError::WriteFailed)` if writing to the keyboard failed.
+    ///
+    /// ```rust,no_run
+    /// # fn test() -> Result<(), wooting_sdk::WootingError> {
+    /// use wooting_sdk::{rgb::RgbKeyboard, Key};
+    ///
+    /// let mut keyboard = RgbKeyboard::open()?;
+    /// // Set the A key to white...
+    /// keyboard.direct_set_key(Key::A, (255, 255, 255))?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Keys that cannot be written directly keep the other colors that the keyboard shows:
+    ///
+    /// ```rust
+    /// # fn test() -> Result<(), wooting_sdk::WootingError> {
+    /// use wooting_sdk::{
+    ///     backend::SimulatedKeyboard,
+    ///     rgb::{Color, RgbFrame, RgbKeyboard},
+    ///     Key,
+    /// };
+    ///
+    /// let simulated = SimulatedKeyboard::new();
+    /// let mut keyboard = RgbKeyboard::new(simulated.clone())?;
+    /// keyboard.commit(&RgbFrame::new(Color::BLUE))?;
+    /// keyboard.direct_set_key(Key::Escape, Color::RED)?;
+    /// keyboard.array_set_single(Key::A, Color::WHITE)?;
+    ///
+    /// // Setting a key on the numpad keeps the notification on Escape..
+    /// keyboard.direct_set_key(Key::NumFive, Color::GREEN)?;
+    /// assert_eq!(simulated.displayed().color(Key::NumFive), Some((0, 255, 0)));
+    /// assert_eq!(simulated.displayed().color(Key::Escape), Some((255, 0, 0)));
+    /// assert_eq!(keyboard.get_key_color(Key::NumFive), Some(Color::GREEN));
+    /// // ..and does not show the change to the color array until it is applied.
+    /// assert_eq!(simulated.displayed().color(Key::A), Some((0, 0, 255)));
+    /// keyboard.array_update()?;
+    /// assert_eq!(simulated.displayed().color(Key::A), Some((255, 255, 255)));
+    /// # Ok(())
+    /// # }
+    /// # test().unwrap();
+    /// ```
+    pub fn direct_set_key<K: IntoMatrixRowColumn, C: Into<Color>>(
+        &mut self,
+        key: K,
+        color: C,
+    ) -> Result<(), WootingError> {
+        let color = color.into();
+        let (row, column) = self.model.matrix_row_and_column(&key)?;
+        self.restore_if_reconnected()?;
+
+        if let Some(key) = self.key_not_set_directly(row, column) {
+            return self.show_through_array(key, Some(color));
+        }
+
+        let written = self
+            .backend
+            .direct_set_key(row, column, color.red, color.green, color.blue);
+        self.check_written(written)?;
+        self.set_displayed_color(row, column, Some(color));
+        Ok(())
+    }
+
+    /// Return the key at the matrix row and column if it cannot be written directly.
+    fn key_not_set_directly(&self, row: u8, column: u8) -> Option<Key> {
+        Key::from_matrix_row_and_column(row, column)
+            .filter(|&key| !can_set_directly(self.model, key))
+    }
+
+    /// Show the key in the given color, or in its original color if `None`, by sending the
+    /// colors that the keyboard is showing as the color array (see `direct_set_key`).
+    fn show_through_array(&mut self, key: Key, color: Option<Color>) -> Result<(), WootingError> {
+        let mut displayed = self.displayed;
+        displayed[key] = color;
+        if self.committed.is_none() && displayed.values().all(Option::is_none) {
+            // Every key is back to its original color, which only a reset can show.
+            return self.reset_all();
+        }
+
+        let mut frame = self.committed.unwrap_or_default();
+        for (key, color) in displayed.iter() {
+            if let Some(color) = *color {
+                frame[key] = color;
+            }
+        }
+
+        let written = self.backend.array_set_full(frame.as_buffer())
+            && (self.auto_update || self.backend.array_update_keyboard());
+        // See `array_unsent`.
+        self.array_unsent = true;
+        self.check_written(written)?;
+        self.displayed = self.shown_colors(&frame);
+        Ok(())
+    }
+
+    /// Directly reset the color of a single key on the keyboard. This will not influence the
+    /// keyboard color array. Use this function for simple amplifications, like a notification.
+    /// Use the array functions if you want to change the entire keyboard.
+    ///
+    /// Keys that cannot be written directly (see `direct_set_key`) are shown in their color in
+    /// the last committed frame, or black if nothing has been committed since the keyboard was
+    /// last reset. If no other key shows a color either, the keyboard is reset instead.
+    ///
+    /// Returns `Err(WootingError::UnsupportedKey)` if the keyboard model does not have the key,
+    /// `Err(WootingError::Disconnected)` if the keyboard is not connected or
+    /// `Err(WootingError::WriteFailed)` if writing to the keyboard failed.
+    ///
+    /// ```rust,no_run
+    /// # fn test() -> Result<(), wooting_sdk::WootingError> {
+    /// use wooting_sdk::{rgb::RgbKeyboard, Key};
+    ///
+    /// let mut keyboard = RgbKeyboard::open()?;
+    /// // Set the A key to white...
+    /// keyboard.direct_set_key(Key::A, (255, 255, 255))?;
+    /// // ..and then reset it back!
+    /// keyboard.direct_reset_key(Key::A)?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Keys that cannot be written directly are reset through the color array:
+    ///
+    /// ```rust
+    /// # fn test() -> Result<(), wooting_sdk::WootingError> {
+    /// use wooting_sdk::{
+    ///     backend::SimulatedKeyboard,
+    ///     rgb::{Color, RgbFrame, RgbKeyboard},
+    ///     Key,
+    /// };
+    ///
+    /// let simulated = SimulatedKeyboard::new();
+    /// let mut keyboard = RgbKeyboard::new(simulated.clone())?;
+    /// keyboard.commit(&RgbFrame::new(Color::BLUE))?;
+    ///
+    /// keyboard.direct_set_key(Key::NumFive, Color::GREEN)?;
+    /// keyboard.direct_reset_key(Key::NumFive)?;
+    /// assert_eq!(simulated.displayed().color(Key::NumFive), Some((0, 0, 255)));
+    /// assert_eq!(keyboard.get_key_color(Key::NumFive), Some(Color::BLUE));
+    /// # Ok(())
+    /// # }
+    /// # test().unwrap();
+    /// ```
+    pub fn direct_reset_key<K: IntoMatrixRowColumn>(&mut self, key: K) -> Result<(), WootingError> {
+        let (row, column) = self.model.matrix_row_and_column(&key)?;
+        self.restore_if_reconnected()?;
+        if let Some(key) = self.key_not_set_directly(row, column) {
+            return self.show_through_array(key, None);
+        }
+
+        let written = self.backend.direct_reset_key(row, column);
+        self.check_written(written)?;
+        self.set_displayed_color(row, column, None);
+        Ok(())
+    }
+
+    /// Apply any updates made by the `array_set_single` and `array_set_full` functions.
+    ///
+    /// Returns `Err(WootingError::Disconnected)` if the keyboard is not connected or
+    /// `Err(WootingError::WriteFailed)` if writing to the keyboard failed.
+    ///
+    /// ```rust,no_run
+    /// # fn test() -> Result<(), wooting_sdk::WootingError> {
+    /// use wooting_sdk::{rgb::RgbKeyboard, Key};
+    ///
+    /// let mut keyboard = RgbKeyboard::open()?;
+    /// // Modify keyboard array so A will be set to white..
+    /// keyboard.array_set_single(Key::A, (255, 255, 255))?;
+    /// // ..and apply the change.
+    /// keyboard.array_update()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn array_update(&mut self) -> Result<(), WootingError> {
+        self.restore_if_reconnected()?;
+        self.send_array_if_unsent()?;
+        let written = self.backend.array_update_keyboard();
+        self.check_written(written)?;
+        self.record_commit();
+        Ok(())
+    }
+
+    /// Show a complete frame on the keyboard, whether or not the auto update flag is set. The
+    /// frame replaces the color array. Colors of keys that the keyboard model does not have are
+    /// ignored.
+    ///
+    /// The frame is compared with the colors that the keyboard is showing: if no more keys
+    /// changed than the sparse threshold (see `set_sparse_threshold`), each of them is written
+    /// directly, otherwise the whole frame is sent as the color array. A frame that changes a key
+    /// that cannot be written directly (see `direct_set_key`) is always sent whole.
+    /// Frames are sent as they are stored, so this does not allocate or rebuild the buffer that
+    /// the RGB SDK takes, and can be called in a loop that animates the keyboard. Returns how the
+    /// frame was sent and how much that took. Once the keyboard has been noticed to be
+    /// disconnected, every key is sent by the next commit, as the keyboard may have been plugged
+    /// back in and lost its colors.
+    ///
+    /// Returns `Err(WootingError::Disconnected)` if the keyboard is not connected or
+    /// `Err(WootingError::WriteFailed)` if writing to the keyboard failed.
+    ///
+    /// ```rust
+    /// # fn test() -> Result<(), wooting_sdk::WootingError> {
+    /// use wooting_sdk::{
+    ///     backend::SimulatedKeyboard,
+    ///     rgb::{Color, CommitStats, CommitStrategy, RgbFrame, RgbKeyboard},
+    ///     Key, KeySet,
+    /// };
+    ///
+    /// let simulated = SimulatedKeyboard::new();
+    /// let mut keyboard = RgbKeyboard::new(simulated.clone())?;
+    ///
+    /// // Light up WASD on a dim background, which changes every key..
+    /// let mut frame = RgbFrame::new((16, 16, 16));
+    /// frame.fill_keys(KeySet::WASD, Color::RED);
+    /// let stats = keyboard.commit(&frame)?;
+    /// assert_eq!(stats.strategy, CommitStrategy::Full);
+    /// assert_eq!((stats.calls, stats.argument_bytes), (2, CommitStats::FULL_ARGUMENT_BYTES));
+    /// assert_eq!(simulated.displayed().color(Key::W), Some((255, 0, 0)));
+    /// assert_eq!(simulated.displayed().color(Key::Q), Some((16, 16, 16)));
+    ///
+    /// // ..then move the highlight along, which only writes the two keys that changed..
+    /// frame[Key::W] = (16, 16, 16).into();
+    /// frame[Key::E] = Color::RED;
+    /// let stats = keyboard.commit(&frame)?;
+    /// assert_eq!(stats.strategy, CommitStrategy::PerKey);
+    /// assert_eq!((stats.changed, stats.calls, stats.argument_bytes), (2, 2, 10));
+    /// assert_eq!(keyboard.get_key_color(Key::W), Some(Color::new(16, 16, 16)));
+    /// assert_eq!(simulated.displayed().color(Key::E), Some((255, 0, 0)));
+    ///
+    /// // ..and committing the same frame again sends nothing.
+    /// assert_eq!(keyboard.commit(&frame)?.strategy, CommitStrategy::Unchanged);
+    ///
+    /// // Changes to the color array start from the last committed frame.
+    /// keyboard.array_set_single(Key::R, Color::BLUE)?;
+    /// keyboard.array_update()?;
+    /// assert_eq!(simulated.displayed().color(Key::E), Some((255, 0, 0)));
+    /// assert_eq!(simulated.displayed().color(Key::W), Some((16, 16, 16)));
+    ///
+    /// // A key on the numpad cannot be written directly, so the frame is sent whole.
+    /// frame[Key::NumFive] = Color::GREEN;
+    /// assert_eq!(keyboard.commit(&frame)?.strategy, CommitStrategy::Full);
+    /// assert_eq!(simulated.displayed().color(Key::NumFive), Some((0, 255, 0)));
+    ///
+    /// // A frame that could not be sent is not treated as shown.
+    /// frame.fill(Color::BLUE);
+    /// simulated.set_writes_fail(true);
+    /// assert!(keyboard.commit(&frame).is_err());
+    /// simulated.set_writes_fail(false);
+    /// assert_eq!(keyboard.commit(&frame)?.strategy, CommitStrategy::Full);
+    /// assert_eq!(simulated.displayed().color(Key::W), Some((0, 0, 255)));
+    ///
+    /// // Every key is sent again after the keyboard is unplugged and plugged back in.
+    /// simulated.set_connected(false);
+    /// simulated.set_connected(true);
+    /// let stats = keyboard.commit(&frame)?;
+    /// assert_eq!((stats.strategy, stats.calls), (CommitStrategy::Full, 2));
+    /// assert_eq!(simulated.displayed().color(Key::W), Some((0, 0, 255)));
+    /// assert_eq!(keyboard.commit(&frame)?.strategy, CommitStrategy::Unchanged);
+    /// # Ok(())
+    /// # }
+    /// # test().unwrap();
+    /// ```
+    pub fn commit(&mut self, frame: &RgbFrame) -> Result<CommitStats, WootingError> {
+        // The frame replaces everything, so there is no need to replay the last one first, or to
+        // check whether the keyboard was plugged back in (see above).
+        let reconnecting = self.disconnected.load(Ordering::SeqCst);
+        if reconnecting {
+            self.displayed = KeyMap::new(None);
+        }
+
+        let model = self.model;
+        let displayed = &self.displayed;
+        let (changed, direct) = Key::iter()
+            .filter(|&key| model.matrix_row_and_column(&key).is_ok())
+            .filter(|&key| displayed[key] != Some(frame[key]))
+            .fold((0, true), |(changed, direct), key| {
+                (changed + 1, direct && can_set_directly(model, key))
+            });
+
+        let stats = if changed == 0 {
+            CommitStats {
+                strategy: CommitStrategy::Unchanged,
+                changed,
+                calls: 0,
+                argument_bytes: 0,
+            }
+        } else if changed <= self.sparse_threshold && direct {
+            self.commit_per_key(frame)?;
+            CommitStats {
+                strategy: CommitStrategy::PerKey,
+                changed,
+                calls: changed,
+                argument_bytes: changed * CommitStats::PER_KEY_ARGUMENT_BYTES,
+            }
+        } else {
+            let written = self.backend.array_set_full(frame.as_buffer())
+                && (self.auto_update || self.backend.array_update_keyboard());
+            if let Err(error) = self.check_written(written) {
+                // See `array_unsent`.
+                self.array_unsent = true;
+                return Err(error);
+            }
+            self.array = *frame;
+            self.array_unsent = false;
+            CommitStats {
+                strategy: CommitStrategy::Full,
+                changed,
+                calls: if self.auto_update { 1 } else { 2 },
+                argument_bytes: CommitStats::FULL_ARGUMENT_BYTES,
+            }
+        };
+
+        // Unless the whole frame was sent, the backend still has the previous color array.
+        self.array_unsent |= self.array != *frame;
+        self.array = *frame;
+        self.record_commit();
+        if reconnecting {
+            self.disconnected.store(false, Ordering::SeqCst);
+        }
+        Ok(stats)
+    }
+
+    /// Directly write each key whose color in the frame differs from the color it is showing.
+    fn commit_per_key(&mut self, frame: &RgbFrame) -> Result<(), WootingError> {
+        for (key, color) in frame.iter() {
+            let (row, column) = match self.model.matrix_row_and_column(&key) {
+                Ok(position) => position,
+                Err(_) => continue,
+            };
+            if self.displayed[key] == Some(color) {
+                continue;
+            }
+
+            let written =
+                self.backend
+                    .direct_set_key(row, column, color.red, color.green, color.blue);
+            self.check_written(written)?;
+            self.displayed[key] = Some(color);
+        }
+        Ok(())
+    }
+
+    /// Set an auto-update trigger after every change with the `array_set_single` and
+    /// `array_set_full` functions. By default, no auto-update trigger is set.
+    ///
+    /// ```rust,no_run
+    /// # fn test() -> Result<(), wooting_sdk::WootingError> {
+    /// use wooting_sdk::{rgb::RgbKeyboard, Key};
+    ///
+    /// let mut keyboard = RgbKeyboard::open()?;
+    /// // Make keyboard array changes apply automatically..
+    /// keyboard.array_auto_update(true);
+    /// // ..and then modify the array so QWERTY are set to white...
+    /// // ..with no need for a call to `array_update`!
+    /// keyboard.array_set_full(&[
+    ///     (Key::Q, (255, 255, 255)),
+    ///     (Key::W, (255, 255, 255)),
+    ///     (Key::E, (255, 255, 255)),
+    ///     (Key::R, (255, 255, 255)),
+    ///     (Key::T, (255, 255, 255)),
+    ///     (Key::Y, (255, 255, 255)),
+    /// ])?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn array_auto_update(&mut self, auto_update: bool) {
+        self.auto_update = auto_update;
+        self.backend.array_auto_update(auto_update)
+    }
+
+    /// Set a single color in the color array. This will not directly update the keyboard
+    /// unless the auto update flag is set (see `array_auto_update`), so it can be called
+    /// frequently (i.e. in a loop that updates the entire keyboard).
+    ///
+    /// Returns `Err(WootingError::UnsupportedKey)` if the keyboard model does not have the key,
+    /// `Err(WootingError::Disconnected)` if the keyboard is not connected or
+    /// `Err(WootingError::WriteFailed)` if the auto update flag is set and writing to the
+    /// keyboard failed.
+    ///
+    /// ```rust,no_run
+    /// # fn test() -> Result<(), wooting_sdk::WootingError> {
+    /// use wooting_sdk::{rgb::RgbKeyboard, Key};
+    ///
+    /// let mut keyboard = RgbKeyboard::open()?;
+    /// // Modify the keyboard array so QWERTY will be set to white..
+    /// keyboard.array_set_single(Key::Q, (255, 255, 255))?;
+    /// keyboard.array_set_single(Key::W, (255, 255, 255))?;
+    /// keyboard.array_set_single(Key::E, (255, 255, 255))?;
+    /// keyboard.array_set_single(Key::R, (255, 255, 255))?;
+    /// keyboard.array_set_single(Key::T, (255, 255, 255))?;
+    /// keyboard.array_set_single(Key::Y, (255, 255, 255))?;
+    /// // ..and apply the change.
+    /// keyboard.array_update()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn array_set_single<K: IntoMatrixRowColumn, C: Into<Color>>(
+        &mut self,
+        key: K,
+        color: C,
+    ) -> Result<(), WootingError> {
+        let color = color.into();
+        let (row, column) = self.model.matrix_row_and_column(&key)?;
+        self.restore_if_reconnected()?;
+        self.send_array_if_unsent()?;
+        let written =
+            self.backend
+                .array_set_single(row, column, color.red, color.green, color.blue);
+        self.check_written(written)?;
+        self.set_array_color(row, column, color);
+        self.commit_if_auto_updated();
+        Ok(())
+    }
+
+    /// Set a complete color array. This will not directly update the keyboard unless the auto
+    /// update flag is set (see `array_auto_update`). Keys that are not in the array are set to
+    /// black. To send the same colors repeatedly, build an `RgbFrame` and use `commit` instead.
+    ///
+    /// Returns `Err(WootingError::UnsupportedKey)` if the keyboard model does not have one of the
+    /// keys, in which case the color array is not changed, `Err(WootingError::Disconnected)` if
+    /// the keyboard is not connected or `Err(WootingError::WriteFailed)` if the auto update flag
+    /// is set and writing to the keyboard failed.
+    ///
+    /// ```rust,no_run
+    /// # fn test() -> Result<(), wooting_sdk::WootingError> {
+    /// use wooting_sdk::{rgb::RgbKeyboard, Key};
+    ///
+    /// let mut keyboard = RgbKeyboard::open()?;
+    /// // Modify the keyboard array so QWERTY will be set to white..
+    /// keyboard.array_set_full(&[
+    ///     (Key::Q, (255, 255, 255)),
+    ///     (Key::W, (255, 255, 255)),
+    ///     (Key::E, (255, 255, 255)),
+    ///     (Key::R, (255, 255, 255)),
+    ///     (Key::T, (255, 255, 255)),
+    ///     (Key::Y, (255, 255, 255)),
+    /// ])?;
+    /// // ..and apply the change.
+    /// keyboard.array_update()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// A color array that could not be set is not applied by a later update:
+    ///
+    /// ```rust
+    /// # fn test() -> Result<(), wooting_sdk::WootingError> {
+    /// use wooting_sdk::{
+    ///     backend::SimulatedKeyboard,
+    ///     rgb::{Color, RgbKeyboard},
+    ///     Key, WootingError,
+    /// };
+    ///
+    /// let simulated = SimulatedKeyboard::new();
+    /// let mut keyboard = RgbKeyboard::new(simulated.clone())?;
+    /// keyboard.set_restore_on_reconnect(false);
+    ///
+    /// simulated.set_connected(false);
+    /// assert_eq!(
+    ///     keyboard.array_set_full(&[(Key::A, Color::RED)]),
+    ///     Err(WootingError::Disconnected)
+    /// );
+    ///
+    /// simulated.set_connected(true);
+    /// keyboard.array_update()?;
+    /// assert_eq!(simulated.displayed().color(Key::A), Some((0, 0, 0)));
+    /// assert_eq!(keyboard.get_key_color(Key::A), Some(Color::BLACK));
+    /// # Ok(())
+    /// # }
+    /// # test().unwrap();
+    /// ```
+    pub fn array_set_full<K: IntoMatrixRowColumn, C: Into<Color> + Copy>(
+        &mut self,
+        array: &[(K, C)],
+    ) -> Result<(), WootingError> {
+        let mut frame = RgbFrame::default();
+        for (key, color) in array {
+            let (row, column) = self.model.matrix_row_and_column(key)?;
+            if let Some(key) = Key::from_matrix_row_and_column(row, column) {
+                frame[key] = (*color).into();
+            }
+        }
+        self.restore_if_reconnected()?;
+        let written = self.backend.array_set_full(frame.as_buffer());
+        if let Err(error) = self.check_written(written) {
+            // See `array_unsent`.
+            self.array_unsent = true;
+            return Err(error);
+        }
+        self.array = frame;
+        self.array_unsent = false;
+        self.commit_if_auto_updated();
+        Ok(())
+    }
+
+    /// Set every key in the set to the same color in the color array. This will not directly
+    /// update the keyboard unless the auto update flag is set (see `array_auto_update`).
+    ///
+    /// Returns `Err(WootingError::UnsupportedKey)` if the keyboard model does not have one of the
+    /// keys, in which case the color array is not changed, `Err(WootingError::Disconnected)` if
+    /// the keyboard is not connected or `Err(WootingError::WriteFailed)` if the auto update flag
+    /// is set and writing to the keyboard failed.
+    ///
+    /// ```rust
+    /// # fn test() -> Result<(), wooting_sdk::WootingError> {
+    /// use wooting_sdk::{backend::SimulatedKeyboard, rgb::RgbKeyboard, Key, KeySet};
+    ///
+    /// let simulated = SimulatedKeyboard::new();
+    /// let mut keyboard = RgbKeyboard::new(simulated.clone())?;
+    /// // Modify the keyboard array so WASD and the arrows will be set to red..
+    /// keyboard.array_fill(KeySet::WASD | KeySet::ARROWS, (255, 0, 0))?;
+    /// // ..and apply the change.
+    /// keyboard.array_update()?;
+    ///
+    /// let red: KeySet = simulated
+    ///     .displayed()
+    ///     .colors()
+    ///     .into_iter()
+    ///     .filter(|&(_, color)| color == (255, 0, 0))
+    ///     .map(|(key, _)| key)
+    ///     .collect();
+    /// assert_eq!(red, KeySet::WASD | KeySet::ARROWS);
+    /// # Ok(())
+    /// # }
+    /// # test().unwrap();
+    /// ```
+    pub fn array_fill<C: Into<Color>>(
+        &mut self,
+        keys: KeySet,
+        color: C,
+    ) -> Result<(), WootingError> {
+        let color = color.into();
+        let positions = keys
+            .iter()
+            .map(|key| self.model.matrix_row_and_column(&key))
+            .collect::<Result<Vec<_>, _>>()?;
+        self.restore_if_reconnected()?;
+        self.send_array_if_unsent()?;
+
+        // Only keys that were written are recorded, so that the copy of the color array agrees
+        // with the keyboard if a write fails part of the way through.
+        let mut result = Ok(());
+        for (row, column) in positions {
+            let written =
+                self.backend
+                    .array_set_single(row, column, color.red, color.green, color.blue);
+            result = self.check_written(written);
+            if result.is_err() {
+                break;
+            }
+            self.set_array_color(row, column, color);
+        }
+        self.commit_if_auto_updated();
+        result
+    }
+
+    /// Record the color of the key at the matrix row and column in the copy of the color array.
+    fn set_array_color(&mut self, row: u8, column: u8, color: Color) {
+        if let Some(key) = Key::from_matrix_row_and_column(row, column) {
+            self.array[key] = color;
+        }
+    }
+
+    /// Record the color that the key at the matrix row and column is showing.
+    fn set_displayed_color(&mut self, row: u8, column: u8, color: Option<Color>) {
+        if let Some(key) = Key::from_matrix_row_and_column(row, column) {
+            self.displayed[key] = color;
+        }
+    }
+
+    /// Restore all colors to those that were originally on the keyboard. Must be called when
+    /// application is closed (this will be invoked when this struct is dropped).
+    ///
+    /// Returns `Err(WootingError::Disconnected)` if the keyboard is not connected or
+    /// `Err(WootingError::WriteFailed)` if writing to the keyboard failed.
+    ///
+    /// ```rust,no_run
+    /// # fn test() -> Result<(), wooting_sdk::WootingError> {
+    /// use wooting_sdk::{rgb::RgbKeyboard, Key};
+    ///
+    /// let mut keyboard = RgbKeyboard::open()?;
+    /// // Set ABC to white..
+    /// keyboard.direct_set_key(Key::A, (255, 255, 255))?;
+    /// keyboard.direct_set_key(Key::B, (255, 255, 255))?;
+    /// keyboard.direct_set_key(Key::C, (255, 255, 255))?;
+    /// // ..and then reset the entire keyboard back to how it was previously.
+    /// keyboard.reset_all()?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn reset_all(&mut self) -> Result<(), WootingError> {
+        let written = self.backend.reset();
+        self.check_written(written)?;
+        self.committed = None;
+        self.displayed = KeyMap::new(None);
+        Ok(())
+    }
+}
+
+/// Can the color of the key be written directly on the model? The RGB SDK cannot directly write
+/// the keys that the Wooting Two has and the Wooting One does not: the numpad and those above it.
+/// Those keys are only shown by sending the whole color array.
+fn can_set_directly(model: KeyboardModel, key: Key) -> bool {
+    model != KeyboardModel::WootingTwo || key.is_available_on(KeyboardModel::WootingOne)
+}
+
+impl RgbKeyboard {
+    /// Create a `RgbKeyboard` for a Wooting Two that performs operations through the
+    /// [`FfiBackend`][ffi].
+    ///
+    /// Returns `Err(WootingError::InUse)` if another `RgbKeyboard` has control of the LEDs.
+    ///
+    /// [ffi]: ../backend/struct.FfiBackend.html
+    pub fn open() -> Result<Self, WootingError> {
+        RgbKeyboard::new(FfiBackend)
+    }
+}
+
+impl<B: RgbBackend> Drop for RgbKeyboard<B> {
+    fn drop(&mut self) {
+        // By restricting all rgb functions to get performed on a struct then we can ensure
+        // that there is something to be dropped and therefore force a reset.
+        let _ = self.reset_all();
+        // Also, make sure that the auto update has been reset.
+        self.array_auto_update(false);
+        // Only then let another keyboard take control.
+        self.backend.release();
+    }
+}
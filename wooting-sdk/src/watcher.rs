@@ -0,0 +1,235 @@
+//! Contains a watcher that notices when a keyboard is connected or disconnected.
+
+use super::backend::{AnalogBackend, FfiBackend, Subscription};
+
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::{self, JoinHandle};
+use std::time::Duration;
+
+/// Represents a change in whether a keyboard is connected.
+#[derive(Clone, Copy, Debug, Hash, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConnectionEvent {
+    /// Indicates that a keyboard was connected.
+    Connected,
+    /// Indicates that the keyboard was disconnected.
+    Disconnected,
+}
+
+/// State shared between a `ConnectionWatcher` and its thread.
+#[derive(Debug)]
+struct WatcherState {
+    connected: bool,
+    stopped: bool,
+    /// Has the backend reported a disconnect since the keyboard was last checked?
+    woken: bool,
+    senders: Vec<Sender<ConnectionEvent>>,
+}
+
+/// Watches for a keyboard being connected and disconnected on a background thread, so that
+/// applications do not have to poll. The SDKs only notice that a keyboard was disconnected when
+/// they fail to use it, so a watcher reads an analog value at an interval, which has no visible
+/// effect on the keyboard. Disconnects that the backend reports in between, such as when a read
+/// by the application fails, are noticed straight away.
+///
+/// The RGB SDK cannot read from the keyboard, and every write that reaches the keyboard changes
+/// its colors, so keyboards are only watched through the Analog SDK.
+///
+/// Events are sent to every receiver returned by `events`. The thread is stopped when the
+/// watcher is dropped.
+///
+/// ```rust
+/// use std::thread;
+/// use std::time::Duration;
+/// use wooting_sdk::{backend::SimulatedKeyboard, ConnectionEvent, ConnectionWatcher};
+///
+/// let simulated = SimulatedKeyboard::new();
+/// let watcher = ConnectionWatcher::with_interval(simulated.clone(), Duration::from_millis(1));
+/// let events = watcher.events();
+/// assert!(watcher.is_connected());
+///
+/// // Unplugging the keyboard is noticed..
+/// simulated.set_connected(false);
+/// let timeout = Duration::from_secs(5);
+/// assert_eq!(events.recv_timeout(timeout), Ok(ConnectionEvent::Disconnected));
+/// assert!(!watcher.wait_for_connection(Duration::from_millis(10)));
+///
+/// // ..as is plugging it back in, which can be waited for.
+/// let plug_in = thread::spawn(move || simulated.set_connected(true));
+/// assert!(watcher.wait_for_connection(timeout));
+/// assert_eq!(events.recv_timeout(timeout), Ok(ConnectionEvent::Connected));
+/// plug_in.join().unwrap();
+/// ```
+#[derive(Debug)]
+pub struct ConnectionWatcher {
+    state: Arc<(Mutex<WatcherState>, Condvar)>,
+    thread: Option<JoinHandle<()>>,
+    _subscription: Subscription,
+}
+
+impl ConnectionWatcher {
+    /// How often the keyboard is checked, unless otherwise specified.
+    pub const DEFAULT_INTERVAL: Duration = Duration::from_millis(500);
+
+    /// Create a watcher that checks the keyboard through the [`FfiBackend`][ffi] every
+    /// `DEFAULT_INTERVAL`.
+    ///
+    /// ```rust,no_run
+    /// use std::time::Duration;
+    /// use wooting_sdk::ConnectionWatcher;
+    ///
+    /// let watcher = ConnectionWatcher::new();
+    /// // Wait for up to ten seconds for a keyboard to be plugged in..
+    /// if watcher.wait_for_connection(Duration::from_secs(10)) {
+    ///     // ..and then print whenever it is unplugged or plugged back in.
+    ///     for event in watcher.events() {
+    ///         println!("{:?}", event);
+    ///     }
+    /// }
+    /// ```
+    ///
+    /// [ffi]: backend/struct.FfiBackend.html
+    pub fn new() -> Self {
+        Self::with_interval(FfiBackend, Self::DEFAULT_INTERVAL)
+    }
+
+    /// Create a watcher that checks the keyboard through the given analog backend every
+    /// `DEFAULT_INTERVAL`.
+    pub fn with_backend<B: AnalogBackend + Send + 'static>(backend: B) -> Self {
+        Self::with_interval(backend, Self::DEFAULT_INTERVAL)
+    }
+
+    /// Create a watcher that checks the keyboard through the given analog backend at the given
+    /// interval. Disconnects that the backend reports are noticed straight away.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use wooting_sdk::{backend::SimulatedKeyboard, ConnectionEvent, ConnectionWatcher};
+    ///
+    /// // Checking once an hour is far too slow to notice anything in this example..
+    /// let simulated = SimulatedKeyboard::new();
+    /// let interval = Duration::from_secs(60 * 60);
+    /// let watcher = ConnectionWatcher::with_interval(simulated.clone(), interval);
+    /// let events = watcher.events();
+    ///
+    /// // ..but a disconnect that the backend reports is noticed straight away.
+    /// simulated.set_connected(false);
+    /// let timeout = Duration::from_secs(5);
+    /// assert_eq!(events.recv_timeout(timeout), Ok(ConnectionEvent::Disconnected));
+    /// assert!(!watcher.is_connected());
+    /// ```
+    pub fn with_interval<B: AnalogBackend + Send + 'static>(
+        backend: B,
+        interval: Duration,
+    ) -> Self {
+        let state = Arc::new((
+            Mutex::new(WatcherState {
+                connected: probe(&backend),
+                stopped: false,
+                woken: false,
+                senders: Vec::new(),
+            }),
+            Condvar::new(),
+        ));
+
+        let woken = state.clone();
+        let subscription = backend.subscribe_disconnected(Box::new(move || {
+            let (lock, condvar) = &*woken;
+            lock.lock().unwrap().woken = true;
+            condvar.notify_all();
+        }));
+
+        let shared = state.clone();
+        let thread = thread::spawn(move || watch(|| probe(&backend), interval, &shared));
+
+        ConnectionWatcher {
+            state,
+            thread: Some(thread),
+            _subscription: subscription,
+        }
+    }
+
+    /// Was the keyboard connected when it was last checked?
+    pub fn is_connected(&self) -> bool {
+        self.state.0.lock().unwrap().connected
+    }
+
+    /// Return a receiver for every event from now on.
+    pub fn events(&self) -> Receiver<ConnectionEvent> {
+        let (sender, receiver) = mpsc::channel();
+        self.state.0.lock().unwrap().senders.push(sender);
+        receiver
+    }
+
+    /// Block until the keyboard is connected or the timeout elapses, whichever is first. Returns
+    /// whether the keyboard is connected.
+    pub fn wait_for_connection(&self, timeout: Duration) -> bool {
+        let (lock, condvar) = &*self.state;
+        let state = lock.lock().unwrap();
+        let (state, _) = condvar
+            .wait_timeout_while(state, timeout, |state| !state.connected)
+            .unwrap();
+        state.connected
+    }
+}
+
+impl Default for ConnectionWatcher {
+    fn default() -> Self {
+        ConnectionWatcher::new()
+    }
+}
+
+impl Drop for ConnectionWatcher {
+    fn drop(&mut self) {
+        let (lock, condvar) = &*self.state;
+        lock.lock().unwrap().stopped = true;
+        condvar.notify_all();
+
+        if let Some(thread) = self.thread.take() {
+            let _ = thread.join();
+        }
+    }
+}
+
+/// Check whether the keyboard is connected. A read is performed first so that the backend notices
+/// if the keyboard has been disconnected since it was last used.
+fn probe<B: AnalogBackend>(backend: &B) -> bool {
+    let _ = backend.read_analog(0, 0);
+    backend.is_connected()
+}
+
+/// Check the keyboard at every interval (or when woken by a disconnect) until the watcher is
+/// stopped, sending an event and waking any waiting threads whenever the keyboard is connected or
+/// disconnected.
+fn watch<P: Fn() -> bool>(probe: P, interval: Duration, shared: &(Mutex<WatcherState>, Condvar)) {
+    let (lock, condvar) = shared;
+    let mut state = lock.lock().unwrap();
+    while !state.stopped {
+        let (mut next, _) = condvar
+            .wait_timeout_while(state, interval, |state| !state.stopped && !state.woken)
+            .unwrap();
+        if next.stopped {
+            return;
+        }
+        next.woken = false;
+
+        // The backend is not used while holding the lock, so that a slow check does not block
+        // `is_connected` and `events`.
+        drop(next);
+        let connected = probe();
+        state = lock.lock().unwrap();
+
+        if connected != state.connected {
+            state.connected = connected;
+            let event = if connected {
+                ConnectionEvent::Connected
+            } else {
+                ConnectionEvent::Disconnected
+            };
+            // Receivers that have been dropped are forgotten.
+            state.senders.retain(|sender| sender.send(event).is_ok());
+            condvar.notify_all();
+        }
+    }
+}